@@ -83,7 +83,7 @@ pub fn criterion_benchmark(c: &mut Criterion) {
                 (send, oneshot_send, oneshot_recv)
             },
             |(mut send, back_send, recv)| async move {
-                let _x = send.send(back_send).await.unwrap();
+                send.send(back_send).await.unwrap();
                 let _y = black_box(recv.await.unwrap());
             },
         )
@@ -21,15 +21,15 @@ impl Actor for Service {
 
     // `stopping` method will be invoked when the actor will be requested
     // to stop its execution.
-    async fn stopping(&mut self) -> ActorAction {
-        println!("Service is stopping");
+    async fn stopping(&mut self, reason: StopReason) -> ActorAction {
+        println!("Service is stopping: {:?}", reason);
         // We could've ignored the stop request by returning `ActorAction::KeepRunning`.
         ActorAction::Stop
     }
 
     // `stopped` method will be invoked once actor is actually stopped.
-    fn stopped(&mut self) {
-        println!("Service has stopped");
+    fn stopped(&mut self, reason: StopReason) {
+        println!("Service has stopped: {:?}", reason);
     }
 }
 
@@ -1,22 +1,37 @@
 //! This example shows how to notify actor at a certain interval.
-//! It also demonstrates how to attach stream to an actor via its address.
 
 use std::time::{Duration, Instant};
 
-use futures::StreamExt;
+use futures::channel::oneshot;
 use messages::prelude::*;
-use tokio_stream::wrappers::IntervalStream;
 
 #[derive(Debug)]
 pub struct Service {
     last_notified: Instant,
+    notified: usize,
+    timer: Option<TimerHandle>,
+    done: Option<oneshot::Sender<()>>,
 }
 
 impl Actor for Service {}
 
+#[derive(Debug)]
+pub struct Start;
+
 #[derive(Debug)]
 pub struct Notification;
 
+#[async_trait]
+impl Handler<Start> for Service {
+    type Result = ();
+
+    async fn handle(&mut self, _input: Start, context: &Context<Self>) {
+        // Ask context to notify us every 100ms.
+        let timer = context.notify_interval(Duration::from_millis(100), || Notification);
+        self.timer = Some(timer);
+    }
+}
+
 #[async_trait]
 impl Notifiable<Notification> for Service {
     async fn notify(&mut self, _input: Notification, _: &Context<Self>) {
@@ -25,13 +40,27 @@ impl Notifiable<Notification> for Service {
             self.last_notified.elapsed().as_millis()
         );
         self.last_notified = Instant::now();
+        self.notified += 1;
+
+        // We want 10 notifications only.
+        if self.notified == 10 {
+            if let Some(timer) = self.timer.take() {
+                timer.cancel();
+            }
+            if let Some(done) = self.done.take() {
+                let _ = done.send(());
+            }
+        }
     }
 }
 
 impl Service {
-    pub fn create() -> Self {
+    pub fn create(done: oneshot::Sender<()>) -> Self {
         Self {
             last_notified: Instant::now(),
+            notified: 0,
+            timer: None,
+            done: Some(done),
         }
     }
 }
@@ -39,16 +68,14 @@ impl Service {
 #[tokio::main]
 async fn main() {
     // Start a service.
-    let address = Service::create().spawn();
-
-    // Attach a stream that will ping the service every 100ms.
-    // It will emit 10 values only.
-    let interval_stream = IntervalStream::new(tokio::time::interval(Duration::from_millis(100)))
-        .take(10)
-        .map(|_| Notification);
+    let (done, notified) = oneshot::channel();
+    let mut address = Service::create(done).spawn();
+    address.send(Start).await.unwrap();
 
-    let join_handle = address.spawn_stream_forwarder(interval_stream);
+    // Wait until service receives all the notifications.
+    notified.await.unwrap();
 
-    // Wait until stream yielded all its values.
-    join_handle.await.unwrap().unwrap();
+    // Timer is cancelled automatically when actor is stopped.
+    address.stop().await;
+    address.wait_for_stop().await;
 }
@@ -23,14 +23,14 @@ impl Actor for Service {
 
     // `stopping` method will be invoked when the actor will be requested
     // to stop its execution.
-    async fn stopping(&mut self) -> ActorAction {
-        println!("Service is stopping");
+    async fn stopping(&mut self, reason: StopReason) -> ActorAction {
+        println!("Service is stopping: {:?}", reason);
         ActorAction::Stop
     }
 
     // `stopped` method will be invoked once actor is actually stopped.
-    fn stopped(&mut self) {
-        println!("Service has stopped");
+    fn stopped(&mut self, reason: StopReason) {
+        println!("Service has stopped: {:?}", reason);
     }
 }
 
@@ -46,7 +46,7 @@ mod server {
                     // We don't send ping messages, do nothing.
                 }
                 Message::Close(_) => {
-                    context.address().stop().await;
+                    context.stop();
                 }
             }
         }
@@ -2,6 +2,8 @@
 //!
 //! For details, see the [`Actor`] documentation.
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use async_trait::async_trait;
 
 use crate::{cfg_runtime, context::Context};
@@ -18,6 +20,109 @@ pub enum ActorAction {
     Stop,
 }
 
+/// Action to be performed after `Actor::on_panic` is called.
+///
+/// In this method, actor can decide how to recover from a panic that
+/// happened in one of its message handlers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicAction {
+    /// Ignore the panic and keep processing messages.
+    Continue,
+    /// Invoke [`Actor::started`] again on the same actor instance and keep processing
+    /// messages. Mailbox and addresses of the actor are preserved.
+    ///
+    /// The actor state is not reset, so `started` is expected to reinitialize it.
+    /// In order to replace the actor with a fresh instance, use a
+    /// [`Supervisor`](crate::supervisor::Supervisor).
+    Reinitialize,
+    /// Stop the actor.
+    Stop,
+}
+
+/// Unique identifier of an actor.
+///
+/// Identifier is assigned once the [`Context`] is created, and it doesn't change
+/// if the actor is restarted by a [`Supervisor`](crate::supervisor::Supervisor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ActorId(u64);
+
+impl ActorId {
+    /// Creates a new unique identifier.
+    pub(crate) fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl std::fmt::Display for ActorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// Notification sent to the actors monitoring another actor via
+/// [`Context::monitor`] once the monitored actor is stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Terminated {
+    /// Identifier of the stopped actor.
+    pub id: ActorId,
+    /// Reason of the actor stop.
+    pub reason: StopReason,
+}
+
+/// Result of the actor execution, returned by [`Context::run`].
+///
+/// Allows to inspect the final state of the actor once it's stopped.
+#[derive(Debug)]
+pub struct RunOutcome<A> {
+    /// Actor instance in the state it was left after [`Actor::stopped`] call.
+    pub actor: A,
+    /// Reason of the actor stop.
+    pub reason: StopReason,
+}
+
+/// Reason of the actor being stopped.
+///
+/// It is passed to the [`Actor::stopping`] and [`Actor::stopped`] methods, and is
+/// returned by [`Address::wait_for_stop`](crate::address::Address::wait_for_stop).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StopReason {
+    /// Stop was requested via [`Address::stop`](crate::address::Address::stop).
+    Requested,
+    /// All the [`Address`] objects connected to the actor were dropped.
+    Disconnected,
+    /// Actor stopped itself via [`Context::stop`].
+    SelfStopped,
+    /// Actor was terminated via [`Context::terminate`].
+    Terminated,
+    /// One of the message handlers panicked, and [`Actor::on_panic`] decided to stop the actor.
+    Panicked,
+    /// Actor was stopped by its [`Supervisor`](crate::supervisor::Supervisor) in order
+    /// to be restarted, because another actor supervised together with it failed.
+    Restarting,
+    /// Actor was stopped by its [`Supervisor`](crate::supervisor::Supervisor), because
+    /// supervised actors failed too often and the supervisor gave up restarting them.
+    SupervisorShutdown,
+    /// Actor was stopped because an actor linked to it via [`Context::link`] failed.
+    Linked,
+    /// [`Context`] was dropped before the actor was stopped, e.g. because the runtime was
+    /// shut down or the `Context` was not launched at all.
+    ///
+    /// Actor is never notified with this reason, it can only be observed via
+    /// [`Address::wait_for_stop`](crate::address::Address::wait_for_stop).
+    Dropped,
+}
+
+impl StopReason {
+    /// Returns `true` if the actor was stopped on purpose, e.g. either via
+    /// [`Address::stop`](crate::address::Address::stop) or by the actor itself.
+    #[must_use]
+    pub fn is_graceful(self) -> bool {
+        matches!(self, Self::Requested | Self::SelfStopped)
+    }
+}
+
 /// Actor is an entity capable of receiving and processing messages.
 ///
 /// Each actor runs in an associated [`Context`] object that is capable
@@ -31,8 +136,8 @@ pub enum ActorAction {
 ///
 /// - `started` method is called once [`Context`] was launched and is about
 ///   to start processing incoming messages.
-/// - `stopping` method is called after either [`Address::stop`] method was
-///   executed, or all the [`Address`] objects connected to an actor were
+/// - `stopping` method is called after either [`Address::stop`] or [`Context::stop`]
+///   method was executed, or all the [`Address`] objects connected to an actor were
 ///   dropped.
 /// - `stopped` method is a notification signaling that the [`Context`] future
 ///   is finishing its execution and after this call `Actor` object will be
@@ -43,6 +148,10 @@ pub enum ActorAction {
 /// Actor can stop in the following scenarios:
 ///
 /// - [`Address::stop`] method was invoked.
+/// - [`Context::stop`] or [`Context::terminate`] method was invoked from within a handler.
+/// - One of the message handlers panicked (see [`Actor::on_panic`]).
+/// - All the [`Address`] objects connected to an actor were dropped
+///   (note that [`WeakAddress`](crate::address::WeakAddress) objects do not keep an actor alive).
 /// - Runtime in which [`Context`] is spawned was shutdown.
 ///
 /// ## Prerequisites
@@ -74,13 +183,13 @@ pub enum ActorAction {
 ///         println!("Actor was started");
 ///     }
 ///
-///     async fn stopping(&mut self) -> ActorAction {
-///         println!("Actor is stopping");
+///     async fn stopping(&mut self, reason: StopReason) -> ActorAction {
+///         println!("Actor is stopping: {:?}", reason);
 ///         ActorAction::Stop
 ///     }
 ///
-///     fn stopped(&mut self) {
-///         println!("Actor is stopped");
+///     fn stopped(&mut self, reason: StopReason) {
+///         println!("Actor is stopped: {:?}", reason);
 ///     }
 /// }
 ///
@@ -104,40 +213,104 @@ pub trait Actor: Unpin + Send + Sync + Sized + 'static {
     /// all the [`Address`] objects will be dropped.
     ///
     /// It is guaranteed that after invocation of this method there will
-    /// be no messages passed to the actor (unless `ActorAction::KeepRunning`).
-    async fn stopping(&mut self) -> ActorAction {
+    /// be no messages passed to the actor (unless `ActorAction::KeepRunning` is returned,
+    /// or the [`StopMode::Drain`](crate::context::StopMode::Drain) is used).
+    ///
+    /// Note that if all the [`Address`] objects were dropped, actor will be
+    /// stopped regardless of the returned value, as it can't receive messages anymore.
+    /// Same applies to the [`Context::terminate`] method.
+    ///
+    /// `reason` describes why the actor is being stopped.
+    async fn stopping(&mut self, _reason: StopReason) -> ActorAction {
         ActorAction::Stop
     }
 
+    /// Method called if one of the message handlers panicked.
+    ///
+    /// `message` is the panic message (if it was a string).
+    ///
+    /// Sender of the message that caused the panic receives
+    /// [`SendError::HandlerPanicked`](crate::errors::SendError::HandlerPanicked).
+    /// Returned value determines what should happen with the actor next.
+    /// Note that the actor is still in the state it was left by the panicked handler,
+    /// so if it decides to keep running, it may need to reset its state first
+    /// (e.g. via [`PanicAction::Reinitialize`]).
+    ///
+    /// If the actor decides to stop, [`Actor::stopping`] is called, but the returned
+    /// value is ignored.
+    ///
+    /// By default, actor is stopped.
+    async fn on_panic(&mut self, _message: &str) -> PanicAction {
+        PanicAction::Stop
+    }
+
     /// Final notification about actor life end. Invoking this method
     /// will only be followed by the destruction of a [`Context`] object.
-    fn stopped(&mut self) {}
+    ///
+    /// `reason` describes why the actor was stopped.
+    fn stopped(&mut self, _reason: StopReason) {}
 
     /// Creates [`Context`] object and starts the message processing loop.
     ///
     /// Future returned by this method should not normally be directly `await`ed,
     /// but rather is expected to be used in some kind of `spawn` function of
     /// the used runtime (e.g. `tokio::spawn` or `async_std::task::spawn`).
-    async fn run(self) {
-        Context::new().run(self).await;
+    async fn run(self) -> RunOutcome<Self> {
+        Context::new().run(self).await
     }
 
     /// Alternative to `run` function that should be used if an actor
     /// needs access to the [`Context`] object to be created (e.g. to
     /// know its own address).
-    async fn create_and_run<F>(f: F)
+    async fn create_and_run<F>(f: F) -> RunOutcome<Self>
     where
         F: FnOnce(&Context<Self>) -> Self + Send,
     {
         let mut context = Context::new();
         let this = f(&mut context);
-        context.run(this).await;
+        context.run(this).await
     }
 }
 
 cfg_runtime! {
+use std::{future::Future, pin::Pin, task::Poll};
+
+use futures::{future::BoxFuture, FutureExt};
+
 use crate::address::Address;
 
+/// Handle of a spawned actor that resolves to the [`RunOutcome`] once the actor is stopped.
+///
+/// Dropping the handle does not stop the actor.
+///
+/// If the actor task panicked (e.g. in [`Actor::started`]), the panic is propagated
+/// to the task awaiting the handle.
+pub struct ActorJoinHandle<A> {
+    inner: BoxFuture<'static, RunOutcome<A>>,
+}
+
+impl<A> std::fmt::Debug for ActorJoinHandle<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ActorJoinHandle").finish_non_exhaustive()
+    }
+}
+
+impl<A: Actor> ActorJoinHandle<A> {
+    pub(crate) fn new(handle: crate::runtime::JoinHandle<RunOutcome<A>>) -> Self {
+        Self {
+            inner: crate::runtime::join(handle).boxed(),
+        }
+    }
+}
+
+impl<A> Future for ActorJoinHandle<A> {
+    type Output = RunOutcome<A>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx)
+    }
+}
+
 /// Extension trait for `Actor` providing more convenient interface when
 /// one of the runtime features is enabled.
 pub trait RuntimeActorExt: Actor {
@@ -148,6 +321,44 @@ pub trait RuntimeActorExt: Actor {
         Context::new().spawn(self)
     }
 
+    /// Spawns an actor using supported runtime.
+    ///
+    /// Returns an address of this actor and a handle that resolves to the
+    /// [`RunOutcome`] once the actor is stopped.
+    ///
+    /// ## Examples
+    ///
+    /// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+    ///
+    /// ```rust
+    /// # use messages::prelude::*;
+    /// struct Counter(u32);
+    ///
+    /// impl Actor for Counter {}
+    ///
+    /// #[async_trait]
+    /// impl Notifiable<u32> for Counter {
+    ///     async fn notify(&mut self, input: u32, context: &Context<Self>) {
+    ///         self.0 += input;
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let (mut addr, handle) = Counter(0).spawn_with_handle();
+    ///    addr.notify(40).await.unwrap();
+    ///    addr.notify(2).await.unwrap();
+    ///    drop(addr);
+    ///
+    ///    let outcome = handle.await;
+    ///    assert_eq!(outcome.actor.0, 42);
+    ///    assert_eq!(outcome.reason, StopReason::Disconnected);
+    /// }
+    /// ```
+    fn spawn_with_handle(self) -> (Address<Self>, ActorJoinHandle<Self>) {
+        Context::new().spawn_with_handle(self)
+    }
+
     /// Same as [`Actor::create_and_run`], but spawns
     /// the future instead of returning it.
     ///
@@ -2,51 +2,115 @@
 //!
 //! See [`Actor`] documentation for details.
 
-use std::sync::Arc;
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
 use crate::{
-    actor::Actor,
+    actor::{Actor, ActorId, StopReason},
     cfg_runtime,
-    context::{InputHandle, Signal},
+    context::{InputHandle, Signal, StopHandle},
     envelope::{EnvelopeProxy, MessageEnvelope, NotificationEnvelope},
     errors::SendError,
     handler::{Handler, Notifiable},
+    mailbox::{MailboxError, MailboxSender, MailboxStats},
+    recipient::{NotifyRecipient, Recipient},
 };
-use futures::{lock::Mutex, Stream, StreamExt};
+use futures::{Stream, StreamExt};
 
 /// `Address` is an object used to communicate with [`Actor`]s.
 ///
 /// Assuming that [`Actor`] is capable of processing messages of a certain
 /// type, the [`Address`] can be used to interact with [`Actor`] by using
 /// either [`Address::send`] (for messages) or [`Address::notify`] (for notifications).
+///
+/// Once all the `Address` objects connected to an [`Actor`] are dropped, the actor
+/// is stopped. If an address should not keep an actor alive (e.g. an actor stores its
+/// own address), [`WeakAddress`] should be used instead.
 pub struct Address<A> {
-    sender: async_channel::Sender<Signal<InputHandle<A>>>,
-    stop_handle: Arc<Mutex<()>>,
+    sender: MailboxSender<A>,
+    stop_handle: StopHandle,
+    strong_count: Arc<AtomicUsize>,
+    id: ActorId,
+    name: Option<Arc<str>>,
 }
 
 impl<A> std::fmt::Debug for Address<A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Address").finish()
+        f.debug_struct("Address")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("connected", &self.connected())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A> PartialEq for Address<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<A> Eq for Address<A> {}
+
+impl<A> std::hash::Hash for Address<A> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
     }
 }
 
 impl<A> Clone for Address<A> {
     fn clone(&self) -> Self {
+        self.strong_count.fetch_add(1, Ordering::Relaxed);
+
         Self {
             sender: self.sender.clone(),
             stop_handle: self.stop_handle.clone(),
+            strong_count: self.strong_count.clone(),
+            id: self.id,
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl<A> Drop for Address<A> {
+    fn drop(&mut self) {
+        if self.strong_count.fetch_sub(1, Ordering::AcqRel) == 1 {
+            // It was the last strong address, so the actor can't receive new messages
+            // anymore. Closing the channel will notify the `Context` about it.
+            self.sender.close();
         }
     }
 }
 
 impl<A> Address<A> {
-    pub(crate) fn new(
-        sender: async_channel::Sender<Signal<InputHandle<A>>>,
-        stop_handle: Arc<Mutex<()>>,
-    ) -> Self {
-        Self {
-            sender,
-            stop_handle,
+    /// Returns the unique identifier of the [`Actor`].
+    #[must_use]
+    pub fn id(&self) -> ActorId {
+        self.id
+    }
+
+    /// Returns the name of the [`Actor`], if it was set via
+    /// [`ContextBuilder::name`](crate::context::ContextBuilder::name).
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Creates a [`WeakAddress`] that points to the same [`Actor`], but does not
+    /// keep it alive.
+    #[must_use]
+    pub fn downgrade(&self) -> WeakAddress<A> {
+        WeakAddress {
+            sender: self.sender.clone(),
+            stop_handle: self.stop_handle.clone(),
+            strong_count: self.strong_count.clone(),
+            id: self.id,
+            name: self.name.clone(),
         }
     }
 
@@ -97,12 +161,11 @@ impl<A> Address<A> {
 
         let message = Box::new(envelope) as Box<dyn EnvelopeProxy<A> + Send + 'static>;
 
-        self.sender
-            .send(Signal::Message(message))
-            .await
-            .map_err(|_| SendError::ReceiverDisconnected)?;
+        self.enqueue(message).await?;
 
-        receiver.await.map_err(|_| SendError::ReceiverDisconnected)
+        receiver
+            .await
+            .unwrap_or(Err(SendError::ReceiverDisconnected))
     }
 
     /// Sends a notification to the [`Actor`] without receiving any kind of response.
@@ -147,12 +210,239 @@ impl<A> Address<A> {
 
         let message = Box::new(envelope) as Box<dyn EnvelopeProxy<A> + Send + 'static>;
 
-        self.sender
-            .send(Signal::Message(message))
+        match self.sender.send(message).await {
+            // Notification was dropped according to the mailbox policy, which is not
+            // an error for the sender.
+            Ok(()) | Err(MailboxError::Dropped(_)) => Ok(()),
+            Err(MailboxError::Full(_)) => Err(SendError::MailboxFull(())),
+            Err(MailboxError::Closed(_)) => Err(SendError::ReceiverDisconnected),
+            Err(MailboxError::Stopping(_)) => Err(SendError::ActorStopping),
+        }
+    }
+
+    /// Version of [`Address::send`] that puts the message into the high-priority lane
+    /// of the mailbox.
+    ///
+    /// High-priority lane is always drained before the normal mailbox, so the message
+    /// will be processed right after the message that is being processed at the moment.
+    /// High-priority lane is unbounded and is not affected by the mailbox policy, so it
+    /// should only be used for control messages.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case associated actor stopped working.
+    pub async fn send_priority<IN>(&mut self, message: IN) -> Result<A::Result, SendError>
+    where
+        A: Actor + Send + Handler<IN> + 'static,
+        IN: Send + 'static,
+        A::Result: Send + Sync + 'static,
+    {
+        let (sender, receiver) = async_oneshot::oneshot();
+        let envelope: MessageEnvelope<A, IN> = MessageEnvelope::new(message, sender);
+
+        let message = Box::new(envelope) as Box<dyn EnvelopeProxy<A> + Send + 'static>;
+
+        self.sender.send_priority(Signal::Message(message))?;
+
+        receiver
             .await
-            .map_err(|_| SendError::ReceiverDisconnected)?;
+            .unwrap_or(Err(SendError::ReceiverDisconnected))
+    }
 
-        Ok(())
+    /// Version of [`Address::notify`] that puts the notification into the high-priority lane
+    /// of the mailbox.
+    ///
+    /// Since high-priority lane is unbounded, this method never waits.
+    /// See [`Address::send_priority`] for details.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case associated actor stopped working.
+    pub fn notify_priority<IN>(&self, message: IN) -> Result<(), SendError>
+    where
+        A: Actor + Send + Notifiable<IN> + 'static,
+        IN: Send + 'static,
+    {
+        let envelope: NotificationEnvelope<A, IN> = NotificationEnvelope::new(message);
+
+        let message = Box::new(envelope) as Box<dyn EnvelopeProxy<A> + Send + 'static>;
+
+        self.sender.send_priority(Signal::Message(message))
+    }
+
+    /// Attempts to send a message to the [`Actor`] without waiting for the free space
+    /// in its mailbox.
+    ///
+    /// Unlike [`Address::send`], this method is not `async`: the message is enqueued
+    /// immediately, and the returned future can be used to receive the response.
+    /// Thus, it can be used from the synchronous code (e.g. from `Drop` implementation).
+    ///
+    /// ## Examples
+    ///
+    /// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+    ///
+    /// ```rust
+    /// # use messages::prelude::*;
+    ///
+    /// struct Sum;
+    ///
+    /// #[async_trait]
+    /// impl Actor for Sum {}
+    ///
+    /// #[async_trait]
+    /// impl Handler<(u8, u8)> for Sum {
+    ///     type Result = u16;
+    ///     // Implementation omitted.
+    ///     # async fn handle(&mut self, (a, b): (u8, u8), context: &Context<Self>) -> u16 {
+    ///     #    (a as u16) + (b as u16)
+    ///     # }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let mut addr = Sum.spawn();
+    ///    let response = addr.try_send((22, 20)).unwrap();
+    ///    assert_eq!(response.await.unwrap(), 42);
+    ///    # addr.stop().await;
+    ///    # addr.wait_for_stop().await;
+    /// }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Will return [`SendError::MailboxFull`] with the message if the mailbox of
+    /// the actor is full, or [`SendError::ReceiverDisconnected`] if associated actor
+    /// stopped working.
+    pub fn try_send<IN>(
+        &self,
+        message: IN,
+    ) -> Result<impl Future<Output = Result<A::Result, SendError>>, SendError<IN>>
+    where
+        A: Actor + Send + Handler<IN> + 'static,
+        IN: Send + 'static,
+        A::Result: Send + Sync + 'static,
+    {
+        let (sender, receiver) = async_oneshot::oneshot();
+        let envelope: MessageEnvelope<A, IN> = MessageEnvelope::new(message, sender);
+
+        let message = Box::new(envelope) as Box<dyn EnvelopeProxy<A> + Send + 'static>;
+
+        self.sender
+            .try_send(message)
+            .map_err(Self::map_try_send_error)?;
+
+        Ok(async move {
+            receiver
+                .await
+                .unwrap_or(Err(SendError::ReceiverDisconnected))
+        })
+    }
+
+    /// Attempts to send a notification to the [`Actor`] without waiting for the free
+    /// space in its mailbox.
+    ///
+    /// Unlike [`Address::notify`], this method is not `async`, thus it can be used from
+    /// the synchronous code (e.g. from `Drop` implementation).
+    ///
+    /// ## Examples
+    ///
+    /// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+    ///
+    /// ```rust
+    /// # use messages::prelude::*;
+    ///
+    /// struct Ping;
+    ///
+    /// #[async_trait]
+    /// impl Actor for Ping {}
+    ///
+    /// #[async_trait]
+    /// impl Notifiable<u8> for Ping {
+    ///     async fn notify(&mut self, input: u8, context: &Context<Self>) {
+    ///         println!("Received number {}", input);
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let mut addr = Ping.spawn();
+    ///    match addr.try_notify(42) {
+    ///        Ok(()) => println!("Notification was enqueued"),
+    ///        Err(SendError::MailboxFull(message)) => println!("Mailbox is full, got {} back", message),
+    ///        Err(err) => println!("Unable to send notification: {}", err),
+    ///    }
+    ///    # addr.stop().await;
+    ///    # addr.wait_for_stop().await;
+    /// }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Will return [`SendError::MailboxFull`] with the message if the mailbox of
+    /// the actor is full, or [`SendError::ReceiverDisconnected`] if associated actor
+    /// stopped working.
+    pub fn try_notify<IN>(&self, message: IN) -> Result<(), SendError<IN>>
+    where
+        A: Actor + Send + Notifiable<IN> + 'static,
+        IN: Send + 'static,
+    {
+        let envelope: NotificationEnvelope<A, IN> = NotificationEnvelope::new(message);
+
+        let message = Box::new(envelope) as Box<dyn EnvelopeProxy<A> + Send + 'static>;
+
+        match self.sender.try_send(message) {
+            Ok(()) | Err(MailboxError::Dropped(_)) => Ok(()),
+            Err(err) => Err(Self::map_try_send_error(err)),
+        }
+    }
+
+    /// Converts an error of the non-blocking send into [`SendError`], returning
+    /// the original message back if the mailbox was full.
+    fn map_try_send_error<IN>(error: MailboxError<InputHandle<A>>) -> SendError<IN>
+    where
+        A: Actor,
+        IN: 'static,
+    {
+        match error {
+            MailboxError::Dropped(envelope) | MailboxError::Full(envelope) => {
+                let message = envelope
+                    .into_message()
+                    .downcast::<IN>()
+                    .expect("Envelope contains message of the different type");
+                SendError::MailboxFull(*message)
+            }
+            MailboxError::Closed(_) => SendError::ReceiverDisconnected,
+            MailboxError::Stopping(_) => SendError::ActorStopping,
+        }
+    }
+
+    /// Puts a message that requires a response into the mailbox.
+    async fn enqueue(&self, message: InputHandle<A>) -> Result<(), SendError>
+    where
+        A: Actor,
+    {
+        self.sender.send(message).await.map_err(|err| match err {
+            // Sender expects a response, so even if the message was dropped
+            // according to the mailbox policy, it's an error.
+            MailboxError::Dropped(_) | MailboxError::Full(_) => SendError::MailboxFull(()),
+            MailboxError::Closed(_) => SendError::ReceiverDisconnected,
+            MailboxError::Stopping(_) => SendError::ActorStopping,
+        })
+    }
+
+    /// Returns statistics of the messages that were not delivered to the [`Actor`]
+    /// because of its mailbox policy.
+    ///
+    /// See [`MailboxConfig`](crate::mailbox::MailboxConfig) for details.
+    #[must_use]
+    pub fn mailbox_stats(&self) -> MailboxStats {
+        self.sender.stats()
+    }
+
+    /// Returns the amount of messages waiting in the mailbox of the [`Actor`].
+    #[must_use]
+    pub fn mailbox_len(&self) -> usize {
+        self.sender.len()
     }
 
     /// Combines provided stream and this `Address` object, returning a future
@@ -161,6 +451,10 @@ impl<A> Address<A> {
     /// [`Actor`] associated with this `Address` must implmenet [`Notifiable`] trait
     /// to process messages from the stream.
     ///
+    /// Note that the forwarder keeps the actor alive until the stream is exhausted.
+    /// If the stream should be owned by the actor instead, consider using
+    /// [`Context::add_stream`](crate::context::Context::add_stream).
+    ///
     /// Future returned by this method should not normally be directly `await`ed,
     /// but rather is expected to be used in some kind of `spawn` function of
     /// the used runtime (e.g. `tokio::spawn` or `async_std::task::spawn`).
@@ -180,6 +474,29 @@ impl<A> Address<A> {
         Ok(())
     }
 
+    /// Converts this `Address` into a [`Recipient`] that can only be used to send
+    /// messages of type `IN`, hiding the type of the [`Actor`].
+    #[must_use]
+    pub fn recipient<IN>(&self) -> Recipient<IN, A::Result>
+    where
+        A: Actor + Send + Handler<IN> + 'static,
+        IN: Send + 'static,
+        A::Result: Send + Sync + 'static,
+    {
+        Recipient::from(self.clone())
+    }
+
+    /// Converts this `Address` into a [`NotifyRecipient`] that can only be used to send
+    /// notifications of type `IN`, hiding the type of the [`Actor`].
+    #[must_use]
+    pub fn notify_recipient<IN>(&self) -> NotifyRecipient<IN>
+    where
+        A: Actor + Send + Notifiable<IN> + 'static,
+        IN: Send + 'static,
+    {
+        NotifyRecipient::from(self.clone())
+    }
+
     /// Returns `true` if `Address` is still connected to the [`Actor`].
     #[must_use]
     pub fn connected(&self) -> bool {
@@ -192,30 +509,217 @@ impl<A> Address<A> {
     /// In order to make sure that the actor is stopped, [`Address::wait_for_stop`]
     /// should be used.
     ///
+    /// Stop request is sent through the high-priority lane of the mailbox, so it will
+    /// be processed right after the message that is being processed at the moment,
+    /// without waiting for the rest of the mailbox. What happens with the messages
+    /// left in the mailbox is defined by the [`StopMode`](crate::context::StopMode) of the actor.
+    ///
     /// Does nothing if address is disconnected from the actor or actor already has
     /// been stopped.
+    #[allow(clippy::unused_async)] // Kept `async` for backward compatibility.
     pub async fn stop(&mut self) {
         // If actor is already stopped, we're fine with it.
-        drop(self.sender.send(Signal::Stop).await);
+        // Stop request goes through the system lane, so it's not delayed by the
+        // messages in the mailbox.
+        drop(self.sender.send_priority(Signal::Stop));
     }
 
     /// Creates a future that waits for actor to be fully stopped.
     ///
     /// Note that this method does not request an actor to stop, it only waits for it
     /// in order to stop actor, [`Address::stop`] should be used.
-    pub async fn wait_for_stop(&self) {
-        // We will only able to obtain the lock when context will release it.
-        // However, we don't want to exit early in case this method is called
-        // before actor is actually started, so we do it in the loop until
-        // the channel is disconnected.
-        while self.connected() {
-            self.stop_handle.lock().await;
+    ///
+    /// Returns the reason of the actor stop.
+    pub async fn wait_for_stop(&self) -> StopReason {
+        self.stop_handle.wait().await
+    }
+}
+
+/// `WeakAddress` is a version of [`Address`] that does not keep an [`Actor`] alive.
+///
+/// Actor is stopped once all the [`Address`] objects connected to it are dropped,
+/// regardless of the amount of existing `WeakAddress` objects. In order to communicate
+/// with an actor, `WeakAddress` must be converted to [`Address`] via [`WeakAddress::upgrade`].
+///
+/// `WeakAddress` can be obtained either via [`Address::downgrade`] or via
+/// [`Context::weak_address`](crate::context::Context::weak_address).
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// struct Ping;
+///
+/// #[async_trait]
+/// impl Actor for Ping {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///    let addr = Ping.spawn();
+///    let weak_addr = addr.downgrade();
+///    assert!(weak_addr.upgrade().is_some());
+///
+///    // Dropping the last `Address` stops the actor.
+///    drop(addr);
+///    weak_addr.wait_for_stop().await;
+///    assert!(weak_addr.upgrade().is_none());
+/// }
+/// ```
+pub struct WeakAddress<A> {
+    sender: MailboxSender<A>,
+    stop_handle: StopHandle,
+    strong_count: Arc<AtomicUsize>,
+    id: ActorId,
+    name: Option<Arc<str>>,
+}
+
+impl<A> std::fmt::Debug for WeakAddress<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WeakAddress")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("connected", &self.connected())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A> Clone for WeakAddress<A> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            stop_handle: self.stop_handle.clone(),
+            strong_count: self.strong_count.clone(),
+            id: self.id,
+            name: self.name.clone(),
         }
     }
 }
 
+impl<A> WeakAddress<A> {
+    pub(crate) fn new(
+        sender: MailboxSender<A>,
+        stop_handle: StopHandle,
+        id: ActorId,
+        name: Option<Arc<str>>,
+    ) -> Self {
+        Self {
+            sender,
+            stop_handle,
+            strong_count: Arc::new(AtomicUsize::new(0)),
+            id,
+            name,
+        }
+    }
+
+    /// Creates a strong [`Address`] regardless of whether other strong addresses exist.
+    ///
+    /// Used by the `Context`, since it's allowed to create addresses before any other
+    /// address exists.
+    pub(crate) fn to_strong(&self) -> Address<A> {
+        self.strong_count.fetch_add(1, Ordering::Relaxed);
+
+        Address {
+            sender: self.sender.clone(),
+            stop_handle: self.stop_handle.clone(),
+            strong_count: self.strong_count.clone(),
+            id: self.id,
+            name: self.name.clone(),
+        }
+    }
+
+    /// Attempts to convert `WeakAddress` into [`Address`].
+    ///
+    /// Returns `None` if all the [`Address`] objects were already dropped or
+    /// the [`Actor`] was stopped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<Address<A>> {
+        let mut count = self.strong_count.load(Ordering::Acquire);
+        loop {
+            if count == 0 || self.sender.is_closed() {
+                return None;
+            }
+
+            match self.strong_count.compare_exchange_weak(
+                count,
+                count + 1,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    return Some(Address {
+                        sender: self.sender.clone(),
+                        stop_handle: self.stop_handle.clone(),
+                        strong_count: self.strong_count.clone(),
+                        id: self.id,
+                        name: self.name.clone(),
+                    })
+                }
+                Err(actual) => count = actual,
+            }
+        }
+    }
+
+    pub(crate) fn mailbox_stats(&self) -> MailboxStats {
+        self.sender.stats()
+    }
+
+    /// Returns the unique identifier of the [`Actor`].
+    #[must_use]
+    pub fn id(&self) -> ActorId {
+        self.id
+    }
+
+    /// Returns the name of the [`Actor`], if it was set via
+    /// [`ContextBuilder::name`](crate::context::ContextBuilder::name).
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Stops the current actor instance with the provided reason.
+    ///
+    /// Interrupt can't be vetoed by the actor, and it doesn't close the mailbox,
+    /// so the `Context` can be used to run a new actor instance.
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    pub(crate) fn interrupt(&self, reason: StopReason) {
+        self.stop_handle.set_interrupt(reason);
+        let _ = self.sender.send_priority(Signal::Interrupt);
+    }
+
+    /// Sends a stop request to the [`Actor`].
+    ///
+    /// See [`Address::stop`] for details.
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    pub(crate) fn request_stop(&self) {
+        drop(self.sender.send_priority(Signal::Stop));
+    }
+
+    /// Returns `true` if the [`Actor`] is fully stopped.
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.stop_handle.is_stopped()
+    }
+
+    /// Returns `true` if `WeakAddress` is still connected to the [`Actor`].
+    #[must_use]
+    pub fn connected(&self) -> bool {
+        !self.sender.is_closed()
+    }
+
+    /// Creates a future that waits for actor to be fully stopped.
+    ///
+    /// See [`Address::wait_for_stop`] for details.
+    pub async fn wait_for_stop(&self) -> StopReason {
+        self.stop_handle.wait().await
+    }
+}
+
 cfg_runtime! {
 
+use std::time::{Duration, Instant};
+
 use crate::{
     handler::Coroutine,
     envelope::CoroutineEnvelope
@@ -235,6 +739,108 @@ impl<A> Address<A> {
         crate::runtime::spawn(self.into_stream_forwarder(stream))
     }
 
+    /// Version of [`Address::send`] that gives up waiting for the response after the
+    /// provided timeout.
+    ///
+    /// See [`Address::send_with_deadline`] for details.
+    ///
+    /// ## Errors
+    ///
+    /// Will return [`SendError::Timeout`] if the response was not received in time, or
+    /// [`SendError::ReceiverDisconnected`] in case associated actor stopped working.
+    pub async fn send_timeout<IN>(
+        &mut self,
+        message: IN,
+        timeout: Duration,
+    ) -> Result<A::Result, SendError>
+    where
+        A: Actor + Send + Handler<IN> + 'static,
+        IN: Send + 'static,
+        A::Result: Send + Sync + 'static,
+    {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.send_with_deadline(message, deadline).await,
+            // Timeout is too big to be ever reached.
+            None => self.send(message).await,
+        }
+    }
+
+    /// Version of [`Address::send`] that gives up waiting for the response once the
+    /// provided deadline is reached.
+    ///
+    /// Deadline covers both waiting for the free space in the mailbox and
+    /// waiting for the message to be processed. If the deadline has already passed
+    /// by the time actor receives the message, the message will not be handled.
+    ///
+    /// ## Examples
+    ///
+    /// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+    ///
+    /// ```rust
+    /// # use messages::prelude::*;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// struct Sleepy;
+    ///
+    /// #[async_trait]
+    /// impl Actor for Sleepy {}
+    ///
+    /// #[async_trait]
+    /// impl Handler<Duration> for Sleepy {
+    ///     type Result = ();
+    ///
+    ///     async fn handle(&mut self, input: Duration, context: &Context<Self>) {
+    ///         tokio::time::sleep(input).await;
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let mut addr = Sleepy.spawn();
+    ///    let deadline = Instant::now() + Duration::from_millis(10);
+    ///    let result = addr.send_with_deadline(Duration::from_millis(100), deadline).await;
+    ///    assert!(matches!(result, Err(SendError::Timeout)));
+    ///    # addr.stop().await;
+    ///    # addr.wait_for_stop().await;
+    /// }
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Will return [`SendError::Timeout`] if the response was not received in time, or
+    /// [`SendError::ReceiverDisconnected`] in case associated actor stopped working.
+    pub async fn send_with_deadline<IN>(
+        &mut self,
+        message: IN,
+        deadline: Instant,
+    ) -> Result<A::Result, SendError>
+    where
+        A: Actor + Send + Handler<IN> + 'static,
+        IN: Send + 'static,
+        A::Result: Send + Sync + 'static,
+    {
+        let (sender, receiver) = async_oneshot::oneshot();
+        let envelope: MessageEnvelope<A, IN> =
+            MessageEnvelope::new(message, sender).with_deadline(deadline);
+
+        let message = Box::new(envelope) as Box<dyn EnvelopeProxy<A> + Send + 'static>;
+
+        let request = async {
+            self.enqueue(message).await?;
+
+            receiver.await.unwrap_or(Err(SendError::ReceiverDisconnected))
+        };
+
+        match crate::runtime::timeout_at(deadline, request).await {
+            // Actor drops the message without responding if the deadline has passed.
+            Some(Err(SendError::ReceiverDisconnected)) if Instant::now() >= deadline => {
+                Err(SendError::Timeout)
+            }
+            Some(result) => result,
+            None => Err(SendError::Timeout),
+        }
+    }
+
 
     /// Sends a message to the [`Actor`] and receives the response.
     /// Unlike in [`Address::send`], `calculate` supports parallel execution.
@@ -268,24 +874,24 @@ impl<A> Address<A> {
     ///    # addr.wait_for_stop().await;
     /// }
     /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case associated actor stopped working.
     pub async fn calculate<IN>(&self, message: IN) -> Result<A::Result, SendError>
     where
         A: Actor + Send + Coroutine<IN> + 'static,
         IN: Send + 'static,
         A::Result: Send + Sync + 'static,
     {
-        let addr = self.sender.clone();
         let (sender, receiver) = async_oneshot::oneshot();
         let envelope: CoroutineEnvelope<A, IN> = CoroutineEnvelope::new(message, sender);
 
         let message = Box::new(envelope) as Box<dyn EnvelopeProxy<A> + Send + 'static>;
 
-        addr
-            .send(Signal::Message(message))
-            .await
-            .map_err(|_| SendError::ReceiverDisconnected)?;
+        self.enqueue(message).await?;
 
-        receiver.await.map_err(|_| SendError::ReceiverDisconnected)
+        receiver.await.unwrap_or(Err(SendError::ReceiverDisconnected))
     }
 }
 }
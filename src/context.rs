@@ -2,20 +2,43 @@
 //!
 //! For details, see the [`Context`] documentation.
 
-use std::{pin::Pin, sync::Arc};
+use std::{
+    any::Any,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+use futures::{
+    stream::{self, BoxStream, SelectAll},
+    FutureExt, Stream, StreamExt,
+};
 
 use crate::{
-    actor::{Actor, ActorAction},
-    address::Address,
+    actor::{Actor, ActorAction, ActorId, PanicAction, RunOutcome, StopReason},
+    address::{Address, WeakAddress},
     cfg_runtime,
-    envelope::EnvelopeProxy,
+    envelope::{EnvelopeProxy, StreamEnvelope},
+    errors::SendError,
+    handler::StreamHandler,
+    mailbox::{mailbox, MailboxConfig, MailboxReceiver, MailboxStats},
+    timer::Timers,
 };
-use futures::{lock::Mutex, StreamExt};
 
 #[derive(Debug)]
 pub(crate) enum Signal<Msg> {
     Message(Msg),
     Stop,
+    /// Stops the current actor instance without closing the mailbox (used by supervisors).
+    #[cfg_attr(
+        not(any(feature = "runtime-tokio", feature = "runtime-async-std")),
+        allow(dead_code)
+    )]
+    Interrupt,
 }
 
 /// Default capacity for the mailbox.
@@ -23,19 +46,232 @@ pub const DEFAULT_CAPACITY: usize = 128;
 
 pub(crate) type InputHandle<A> = Box<dyn EnvelopeProxy<A> + Send + 'static>;
 
+type StreamHandle<A> = BoxStream<'static, InputHandle<A>>;
+
+/// Extracts the message from the panic payload.
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// Handle used by addresses to wait for the actor to stop.
+#[derive(Debug, Clone)]
+pub(crate) struct StopHandle {
+    receiver: async_channel::Receiver<()>,
+    reason: Arc<Mutex<Option<StopReason>>>,
+    #[cfg_attr(
+        not(any(feature = "runtime-tokio", feature = "runtime-async-std")),
+        allow(dead_code)
+    )]
+    interrupt: Arc<Mutex<Option<StopReason>>>,
+}
+
+impl StopHandle {
+    /// Waits until the `Context` is dropped and returns the reason of the actor stop.
+    pub(crate) async fn wait(&self) -> StopReason {
+        // Nothing is ever sent via the channel, so it only returns once it's closed.
+        let _ = self.receiver.recv().await;
+        let reason = *self.reason.lock().unwrap_or_else(PoisonError::into_inner);
+        reason.unwrap_or(StopReason::Dropped)
+    }
+
+    /// Returns `true` if the `Context` was dropped.
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.receiver.is_closed()
+    }
+
+    /// Sets the reason to stop the current actor instance.
+    /// It is applied once the `Signal::Interrupt` is received.
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    pub(crate) fn set_interrupt(&self, reason: StopReason) {
+        *self
+            .interrupt
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(reason);
+    }
+}
+
+/// Defines what happens with the messages left in the mailbox once the actor
+/// agrees to stop after [`Address::stop`] or [`Context::stop`] call.
+///
+/// Regardless of the mode, the messages are not handled if the actor is stopped
+/// for any other reason (e.g. it was terminated or it panicked).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopMode {
+    /// Actor is stopped right away, and the messages left in the mailbox are dropped,
+    /// so their senders receive [`SendError::ReceiverDisconnected`].
+    ///
+    /// This is the default mode.
+    Immediate,
+    /// Mailbox is closed, so new messages are rejected with [`SendError::ActorStopping`],
+    /// and the messages that are already queued are handled until the mailbox is empty
+    /// or the provided timeout expires. Messages that were not handled in time are
+    /// rejected with [`SendError::ActorStopping`].
+    ///
+    /// Note that messages are handled *after* the [`Actor::stopping`] call. With one of the
+    /// runtime features enabled, handler that is still running once the timeout expires
+    /// is interrupted, and the sender of its message receives [`SendError::ActorStopping`].
+    /// Without a runtime, the timeout is only checked between the messages.
+    Drain(Duration),
+    /// Mailbox is closed, and all the queued messages are rejected with
+    /// [`SendError::ActorStopping`] without being handled.
+    Reject,
+}
+
+#[allow(clippy::derivable_impls)] // `#[default]` attribute is not supported by older compilers.
+impl Default for StopMode {
+    fn default() -> Self {
+        Self::Immediate
+    }
+}
+
+/// Builder for the [`Context`].
+///
+/// ## Examples
+///
+/// ```rust
+/// # use messages::{prelude::*, mailbox::{MailboxConfig, MailboxPolicy}};
+/// struct Database;
+///
+/// impl Actor for Database {}
+///
+/// let context: Context<Database> = Context::builder()
+///     .name("database")
+///     .mailbox_config(MailboxConfig::new(16).with_policy(MailboxPolicy::DropNewest))
+///     .build();
+/// assert_eq!(context.name(), Some("database"));
+/// assert_eq!(context.address().name(), Some("database"));
+/// ```
+pub struct ContextBuilder<ACTOR> {
+    config: MailboxConfig,
+    name: Option<Arc<str>>,
+    stop_mode: StopMode,
+    _marker: std::marker::PhantomData<fn() -> ACTOR>,
+}
+
+impl<ACTOR> std::fmt::Debug for ContextBuilder<ACTOR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextBuilder")
+            .field("config", &self.config)
+            .field("name", &self.name)
+            .field("stop_mode", &self.stop_mode)
+            .finish()
+    }
+}
+
+impl<ACTOR> Default for ContextBuilder<ACTOR>
+where
+    ACTOR: 'static + Send + Actor + Unpin,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ACTOR> ContextBuilder<ACTOR>
+where
+    ACTOR: 'static + Send + Actor + Unpin,
+{
+    /// Creates a new builder with the default mailbox configuration and without a name.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: MailboxConfig::default(),
+            name: None,
+            stop_mode: StopMode::default(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets a human-readable name of the actor.
+    ///
+    /// Name is only used for diagnostics (e.g. it's a part of the `Debug` output
+    /// of the addresses), and doesn't have to be unique.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into().into());
+        self
+    }
+
+    /// Sets the capacity of the mailbox.
+    #[must_use]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.config = MailboxConfig::new(capacity).with_policy(self.config.policy());
+        self
+    }
+
+    /// Sets the mailbox configuration.
+    ///
+    /// See [`MailboxConfig`] for details.
+    #[must_use]
+    pub fn mailbox_config(mut self, config: MailboxConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the way messages left in the mailbox are treated once the actor is stopped.
+    ///
+    /// See [`StopMode`] for details.
+    #[must_use]
+    pub fn stop_mode(mut self, stop_mode: StopMode) -> Self {
+        self.stop_mode = stop_mode;
+        self
+    }
+
+    /// Creates the [`Context`].
+    #[must_use]
+    pub fn build(self) -> Context<ACTOR> {
+        Context::from_builder(self)
+    }
+}
+
 /// `Context` represents an environment in which actor is being executed.
 ///
 /// It is capable of transferring incoming messages to the actor, providing
 /// actor's address and managing it lifetime (e.g. stopping it).
 pub struct Context<ACTOR> {
-    receiver: async_channel::Receiver<Signal<InputHandle<ACTOR>>>,
-    address: Address<ACTOR>,
-    stop_handle: Arc<Mutex<()>>,
+    receiver: MailboxReceiver<ACTOR>,
+    address: WeakAddress<ACTOR>,
+    /// Addresses use the receiving part of this channel to wait for the actor to stop.
+    /// Nothing is sent via this channel: it's closed once the `Context` is dropped.
+    _stop_sender: async_channel::Sender<()>,
+    /// Reason of the actor stop, set right before the `Context` is dropped.
+    stop_reason: Arc<Mutex<Option<StopReason>>>,
+    timers: Timers,
+    /// Streams added via `Context::add_stream` that are not yet polled by the run loop.
+    pending_streams: Mutex<Vec<StreamHandle<ACTOR>>>,
+    /// Set by `Context::stop`, checked once the current envelope is processed.
+    stop_requested: AtomicBool,
+    /// Set by `Context::terminate`, checked once the current envelope is processed.
+    terminate_requested: AtomicBool,
+    /// Reason to stop the current actor instance, set via `WeakAddress::interrupt`.
+    /// Checked once the `Signal::Interrupt` is received.
+    interrupt: Arc<Mutex<Option<StopReason>>>,
+    stop_mode: StopMode,
 }
 
 impl<ACTOR> std::fmt::Debug for Context<ACTOR> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Context").finish()
+        f.debug_struct("Context")
+            .field("id", &self.address.id())
+            .field("name", &self.address.name())
+            .field("connected", &self.address.connected())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<ACTOR> Drop for Context<ACTOR> {
+    fn drop(&mut self) {
+        // Mailbox may keep a receiver to apply its policy, so we have to explicitly
+        // close the channels to notify all the addresses.
+        self.receiver.close();
+        self.timers.cancel_all();
     }
 }
 
@@ -61,64 +297,361 @@ where
     /// Creates a new `Context` object with custom capacity.
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
-        let (sender, receiver) = async_channel::bounded(capacity);
+        Self::with_config(MailboxConfig::new(capacity))
+    }
+
+    /// Creates a new `Context` object with custom mailbox configuration.
+    ///
+    /// See [`MailboxConfig`] for details.
+    #[must_use]
+    pub fn with_config(config: MailboxConfig) -> Self {
+        Self::builder().mailbox_config(config).build()
+    }
+
+    /// Creates a [`ContextBuilder`] that allows to configure the `Context`
+    /// before creating it.
+    #[must_use]
+    pub fn builder() -> ContextBuilder<ACTOR> {
+        ContextBuilder::new()
+    }
+
+    fn from_builder(builder: ContextBuilder<ACTOR>) -> Self {
+        let (sender, receiver) = mailbox(builder.config);
 
-        let stop_handle = Arc::new(Mutex::new(()));
-        let address = Address::new(sender, stop_handle.clone());
+        let (stop_sender, stop_receiver) = async_channel::bounded(1);
+        let stop_reason = Arc::new(Mutex::new(None));
+        let interrupt = Arc::new(Mutex::new(None));
+        let stop_handle = StopHandle {
+            receiver: stop_receiver,
+            reason: stop_reason.clone(),
+            interrupt: interrupt.clone(),
+        };
+        let address = WeakAddress::new(sender, stop_handle, ActorId::next(), builder.name);
 
         Self {
             receiver,
             address,
-            stop_handle,
+            _stop_sender: stop_sender,
+            stop_reason,
+            timers: Timers::default(),
+            pending_streams: Mutex::default(),
+            stop_requested: AtomicBool::new(false),
+            terminate_requested: AtomicBool::new(false),
+            interrupt,
+            stop_mode: builder.stop_mode,
         }
     }
 
+    /// Returns the unique identifier of the actor.
+    #[must_use]
+    pub fn id(&self) -> ActorId {
+        self.address.id()
+    }
+
+    /// Returns the name of the actor, if it was set via [`ContextBuilder::name`].
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.address.name()
+    }
+
     /// Returns an address of the actor.
+    ///
+    /// Note that an actor is stopped once all of its addresses are dropped,
+    /// so if an actor needs to store its own address, [`Context::weak_address`]
+    /// should be used instead.
     #[must_use]
     pub fn address(&self) -> Address<ACTOR> {
+        self.address.to_strong()
+    }
+
+    /// Returns statistics of the messages that were not delivered to the actor
+    /// because of its mailbox policy.
+    #[must_use]
+    pub fn mailbox_stats(&self) -> MailboxStats {
+        self.address.mailbox_stats()
+    }
+
+    /// Returns a weak address of the actor, which does not prevent the actor
+    /// from being stopped once all the [`Address`] objects are dropped.
+    #[must_use]
+    pub fn weak_address(&self) -> WeakAddress<ACTOR> {
         self.address.clone()
     }
 
+    /// Requests the actor to stop.
+    ///
+    /// Unlike [`Address::stop`], this method does not send anything to the mailbox,
+    /// so it can be safely used from within the message handler. Request is processed
+    /// right after the current message is handled (or right after [`Actor::started`] if
+    /// the actor is not running yet): [`Actor::stopping`] is called and the actor may
+    /// decide to keep running.
+    pub fn stop(&self) {
+        self.stop_requested.store(true, Ordering::Release);
+    }
+
+    /// Requests the actor to stop regardless of the value returned by [`Actor::stopping`].
+    ///
+    /// Same as [`Context::stop`], request is processed right after the current message
+    /// is handled.
+    pub fn terminate(&self) {
+        self.terminate_requested.store(true, Ordering::Release);
+    }
+
+    /// Attaches a stream to the actor.
+    ///
+    /// Stream is polled by the actor together with its mailbox, and every item of the
+    /// stream is passed to [`StreamHandler::handle`]. Unlike [`Address::spawn_stream_forwarder`],
+    /// stream does not keep the actor alive, and it is dropped once the actor is stopped.
+    ///
+    /// If the stream is added from within a message handler, it will be polled once
+    /// the handler is completed.
+    pub fn add_stream<S>(&self, stream: S)
+    where
+        S: Stream + Send + 'static,
+        S::Item: Send + 'static,
+        ACTOR: StreamHandler<S::Item>,
+    {
+        let started = stream::once(async { Self::stream_envelope(StreamEnvelope::started()) });
+        let items = stream.map(|item| Self::stream_envelope(StreamEnvelope::item(item)));
+        let finished = stream::once(async { Self::stream_envelope(StreamEnvelope::Finished) });
+
+        self.pending_streams
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(started.chain(items).chain(finished).boxed());
+    }
+
+    fn stream_envelope<IN>(envelope: StreamEnvelope<ACTOR, IN>) -> InputHandle<ACTOR>
+    where
+        ACTOR: StreamHandler<IN>,
+        IN: Send + 'static,
+    {
+        Box::new(envelope)
+    }
+
+    /// Receives the next signal either from the mailbox or from one of the attached streams.
+    async fn next_signal(
+        &mut self,
+        streams: &mut SelectAll<StreamHandle<ACTOR>>,
+    ) -> Option<Signal<InputHandle<ACTOR>>> {
+        #![allow(clippy::mut_mut)] // Warning is spawned because of `futures::select`.
+
+        let pending_streams = self
+            .pending_streams
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        streams.extend(pending_streams.drain(..));
+        if streams.is_empty() {
+            return self.receiver.recv().await;
+        }
+
+        futures::select_biased! {
+            signal = self.receiver.recv().fuse() => signal,
+            envelope = streams.select_next_some() => Some(Signal::Message(envelope)),
+        }
+    }
+
     /// Starts the message handling routine.
     ///
     /// Future returned by this method should not normally be directly `await`ed,
     /// but rather is expected to be used in some kind of `spawn` function of
     /// the used runtime (e.g. `tokio::spawn` or `async_std::task::spawn`).
-    pub async fn run(mut self, mut actor: ACTOR) {
-        #![allow(clippy::mut_mut)] // Warning is spawned because of `futures::select`.
-
-        // Acquire the lock on the mutex so addresses can be used to `await` until
-        // the actor is stopped.
-        let stop_handle = self.stop_handle.clone();
-        let _mutex_handle = stop_handle.lock().await;
+    ///
+    /// Returned [`RunOutcome`] contains the actor in its final state and the reason
+    /// of the actor stop.
+    pub async fn run(mut self, actor: ACTOR) -> RunOutcome<ACTOR> {
+        let outcome = self.run_instance(actor).await;
+        self.finish(outcome.reason);
+        outcome
+    }
 
+    /// Runs a single actor instance until it's stopped, keeping the mailbox open.
+    ///
+    /// This allows supervisors to restart an actor without invalidating its addresses.
+    pub(crate) async fn run_instance(&mut self, mut actor: ACTOR) -> RunOutcome<ACTOR> {
         actor.started().await;
 
-        let mut running = true;
-        while running {
-            match self.receiver.next().await {
-                Some(Signal::Message(mut envelope)) => {
-                    let actor_pin = Pin::new(&mut actor);
-                    let self_pin = Pin::new(&self);
-                    envelope.handle(actor_pin, self_pin).await;
-                }
-                Some(Signal::Stop) | None => {
-                    // Notify actor about being stopped.
-                    if let ActorAction::Stop = actor.stopping().await {
-                        // Actor agreed to stop, so actually stop the loop.
-                        running = false;
+        let mut streams = SelectAll::new();
+        let reason = loop {
+            // Stop may be requested by a handler, or even before the actor was started.
+            if self.terminate_requested.load(Ordering::Acquire) {
+                // Actor is still notified, but it can't keep running.
+                actor.stopping(StopReason::Terminated).await;
+                break StopReason::Terminated;
+            }
+            let reason = if self.stop_requested.swap(false, Ordering::AcqRel) {
+                StopReason::SelfStopped
+            } else {
+                match self.next_signal(&mut streams).await {
+                    Some(Signal::Message(mut envelope)) => {
+                        // Sender is not waiting for the response anymore, no need to handle the message.
+                        if matches!(envelope.deadline(), Some(deadline) if deadline <= Instant::now())
+                        {
+                            continue;
+                        }
+
+                        if let Err(panic) = self.handle_envelope(&mut actor, &mut envelope).await {
+                            match actor.on_panic(panic_message(&*panic)).await {
+                                PanicAction::Continue => {}
+                                PanicAction::Reinitialize => actor.started().await,
+                                PanicAction::Stop => {
+                                    actor.stopping(StopReason::Panicked).await;
+                                    break StopReason::Panicked;
+                                }
+                            }
+                        }
+
+                        // Handler may have requested the actor to stop, which is checked
+                        // before receiving the next signal.
+                        continue;
+                    }
+                    Some(Signal::Stop) => StopReason::Requested,
+                    Some(Signal::Interrupt) => match self.take_interrupt() {
+                        Some(reason) => {
+                            // Interrupts can't be vetoed.
+                            actor.stopping(reason).await;
+                            break reason;
+                        }
+                        // Interrupt was meant for the previous actor instance.
+                        None => continue,
+                    },
+                    None => {
+                        // All the addresses were dropped, so actor can't receive messages anymore.
+                        // We still notify actor, but it's not possible to keep running.
+                        actor.stopping(StopReason::Disconnected).await;
+                        break StopReason::Disconnected;
                     }
                 }
+            };
+
+            // Notify actor about being stopped.
+            if let ActorAction::Stop = actor.stopping(reason).await {
+                // Actor agreed to stop, so actually stop the loop.
+                break reason;
             }
+        };
+
+        if matches!(reason, StopReason::Requested | StopReason::SelfStopped) {
+            self.drain(&mut actor).await;
         }
 
+        // Actor won't receive messages anymore, so there is no point in running timers
+        // or polling streams.
+        self.timers.cancel_all();
+        drop(streams);
+        self.pending_streams
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+
         // Notify actor that it was fully stopped.
-        actor.stopped();
+        actor.stopped(reason);
+        RunOutcome { actor, reason }
+    }
+
+    /// Passes the message to the actor, catching the panic of the handler.
+    async fn handle_envelope(
+        &self,
+        actor: &mut ACTOR,
+        envelope: &mut InputHandle<ACTOR>,
+    ) -> Result<(), Box<dyn Any + Send>> {
+        AssertUnwindSafe(envelope.handle(Pin::new(actor), Pin::new(self)))
+            .catch_unwind()
+            .await
+    }
+
+    /// Treats the messages left in the mailbox according to the [`StopMode`].
+    async fn drain(&mut self, actor: &mut ACTOR) {
+        let deadline = match self.stop_mode {
+            StopMode::Immediate => return,
+            StopMode::Drain(timeout) => Instant::now().checked_add(timeout),
+            StopMode::Reject => Some(Instant::now()),
+        };
+
+        // Nothing can be sent to the mailbox from now on.
+        self.receiver.close_for_stop();
+        while !matches!(deadline, Some(deadline) if Instant::now() >= deadline) {
+            let mut envelope = match self.receiver.try_recv() {
+                Some(Signal::Message(envelope)) => envelope,
+                // Actor is already stopping.
+                Some(Signal::Stop | Signal::Interrupt) => continue,
+                None => break,
+            };
+            if matches!(envelope.deadline(), Some(deadline) if deadline <= Instant::now()) {
+                continue;
+            }
+
+            #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+            let handled = match deadline {
+                Some(deadline) => {
+                    crate::runtime::timeout_at(deadline, self.handle_envelope(actor, &mut envelope))
+                        .await
+                }
+                None => Some(self.handle_envelope(actor, &mut envelope).await),
+            };
+            #[cfg(not(any(feature = "runtime-tokio", feature = "runtime-async-std")))]
+            let handled = Some(self.handle_envelope(actor, &mut envelope).await);
+
+            match handled {
+                Some(Ok(())) => {}
+                Some(Err(panic)) => match actor.on_panic(panic_message(&*panic)).await {
+                    PanicAction::Continue => {}
+                    PanicAction::Reinitialize => actor.started().await,
+                    PanicAction::Stop => break,
+                },
+                // Handler didn't finish in time and was interrupted.
+                None => {
+                    envelope.reject(SendError::ActorStopping);
+                    break;
+                }
+            }
+            if self.terminate_requested.load(Ordering::Acquire) {
+                break;
+            }
+        }
+        self.reject_queued();
+    }
+
+    /// Rejects all the messages left in the mailbox.
+    fn reject_queued(&self) {
+        while let Some(signal) = self.receiver.try_recv() {
+            if let Signal::Message(envelope) = signal {
+                envelope.reject(SendError::ActorStopping);
+            }
+        }
+    }
+
+    /// Finalizes the actor execution: sets the reason of the actor stop reported
+    /// to the addresses once the `Context` is dropped, and rejects the messages
+    /// left in the mailbox (unless the [`StopMode::Immediate`] is used).
+    pub(crate) fn finish(&self, reason: StopReason) {
+        if self.stop_mode != StopMode::Immediate {
+            self.receiver.close_for_stop();
+            self.reject_queued();
+        }
+
+        *self
+            .stop_reason
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(reason);
+    }
+
+    fn take_interrupt(&self) -> Option<StopReason> {
+        self.interrupt
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
     }
 }
 
 cfg_runtime! {
-use futures::FutureExt;
+use futures::future::{AbortHandle, Abortable};
+
+use crate::{
+    actor::{ActorJoinHandle, Terminated},
+    handler::Notifiable,
+    timer::TimerHandle,
+};
 
 impl<ACTOR> Context<ACTOR>
 where
@@ -130,5 +663,192 @@ where
         let _handle = crate::runtime::spawn(self.run(actor)).boxed();
         address
     }
+
+    /// Spawns an actor and returns its address together with a handle
+    /// that resolves to the [`RunOutcome`] once the actor is stopped.
+    pub fn spawn_with_handle(self, actor: ACTOR) -> (Address<ACTOR>, ActorJoinHandle<ACTOR>) {
+        let address = self.address();
+        let handle = ActorJoinHandle::new(crate::runtime::spawn(self.run(actor)));
+        (address, handle)
+    }
+
+    /// Sends a notification to the actor itself once the provided delay has passed.
+    ///
+    /// Returned [`TimerHandle`] can be used to cancel the notification. Timer is
+    /// cancelled automatically once the actor is stopped.
+    ///
+    /// ## Examples
+    ///
+    /// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+    ///
+    /// ```rust
+    /// # use messages::prelude::*;
+    /// # use std::time::Duration;
+    /// struct Reminder;
+    ///
+    /// struct Remind;
+    ///
+    /// #[async_trait]
+    /// impl Actor for Reminder {}
+    ///
+    /// #[async_trait]
+    /// impl Notifiable<Remind> for Reminder {
+    ///     async fn notify(&mut self, _: Remind, context: &Context<Self>) {
+    ///         println!("Time is up!");
+    ///     }
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl Handler<Duration> for Reminder {
+    ///     type Result = ();
+    ///
+    ///     async fn handle(&mut self, delay: Duration, context: &Context<Self>) {
+    ///         context.notify_later(Remind, delay);
+    ///     }
+    /// }
+    /// ```
+    pub fn notify_later<IN>(&self, message: IN, delay: Duration) -> TimerHandle
+    where
+        ACTOR: Notifiable<IN>,
+        IN: Send + 'static,
+    {
+        let address = self.weak_address();
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let timer_abort_handle = abort_handle.clone();
+        let timer = async move {
+            crate::runtime::sleep(delay).await;
+            if let Some(mut address) = address.upgrade() {
+                let _ = address.notify(message).await;
+            }
+            // Mark the timer as finished.
+            timer_abort_handle.abort();
+        };
+
+        self.spawn_task(timer, registration, abort_handle)
+    }
+
+    /// Sends a notification created by `factory` to the actor itself every `period`.
+    /// The first notification is sent once the `period` has passed.
+    ///
+    /// Returned [`TimerHandle`] can be used to stop the notifications. Timer is
+    /// cancelled automatically once the actor is stopped.
+    pub fn notify_interval<IN, F>(&self, period: Duration, factory: F) -> TimerHandle
+    where
+        ACTOR: Notifiable<IN>,
+        IN: Send + 'static,
+        F: Fn() -> IN + Send + 'static,
+    {
+        let address = self.weak_address();
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let timer_abort_handle = abort_handle.clone();
+        let timer = async move {
+            let mut next_tick = Instant::now() + period;
+            loop {
+                crate::runtime::sleep(next_tick.saturating_duration_since(Instant::now())).await;
+                next_tick += period;
+
+                let delivered = match address.upgrade() {
+                    Some(mut address) => address.notify(factory()).await.is_ok(),
+                    None => false,
+                };
+                if !delivered {
+                    break;
+                }
+            }
+            // Mark the timer as finished.
+            timer_abort_handle.abort();
+        };
+
+        self.spawn_task(timer, registration, abort_handle)
+    }
+
+    /// Monitors another actor: once it's stopped, this actor receives
+    /// a [`Terminated`] notification.
+    ///
+    /// If the monitored actor is already stopped, notification is sent right away.
+    /// Monitoring does not keep the monitored actor alive, and it's cancelled once
+    /// this actor is stopped.
+    pub fn monitor<B>(&self, address: &Address<B>)
+    where
+        ACTOR: Notifiable<Terminated>,
+        B: Actor,
+    {
+        let monitored = address.downgrade();
+        let watcher = self.weak_address();
+        let (abort_handle, registration) = AbortHandle::new_pair();
+        let monitor_abort_handle = abort_handle.clone();
+        let monitor = async move {
+            let terminated = Terminated {
+                id: monitored.id(),
+                reason: monitored.wait_for_stop().await,
+            };
+            if let Some(mut watcher) = watcher.upgrade() {
+                let _ = watcher.notify(terminated).await;
+            }
+            // Mark the monitor as finished, so it's pruned from the timers.
+            monitor_abort_handle.abort();
+        };
+
+        self.spawn_task(monitor, registration, abort_handle);
+    }
+
+    /// Links this actor with another one: once either of them fails
+    /// (i.e. is stopped with [`StopReason::Panicked`]), the other one is stopped
+    /// with [`StopReason::Linked`].
+    ///
+    /// Actor stopped because of the link can't keep running, regardless of the value
+    /// returned by [`Actor::stopping`]. Link does not keep any of the actors alive.
+    pub fn link<B>(&self, address: &Address<B>)
+    where
+        B: Actor,
+    {
+        use futures::future::{select, Either};
+
+        let this = self.weak_address();
+        let other = address.downgrade();
+        // Unlike monitors, link must outlive the actor, so it's not cancelled on stop.
+        let _handle = crate::runtime::spawn(async move {
+            let this_stopped = this.wait_for_stop();
+            let other_stopped = other.wait_for_stop();
+            futures::pin_mut!(this_stopped, other_stopped);
+
+            match select(this_stopped, other_stopped).await {
+                Either::Left((StopReason::Panicked, _)) => other.interrupt(StopReason::Linked),
+                Either::Right((StopReason::Panicked, _)) => this.interrupt(StopReason::Linked),
+                _ => {}
+            }
+        });
+    }
+
+    fn spawn_task<F>(
+        &self,
+        task: F,
+        registration: futures::future::AbortRegistration,
+        abort_handle: AbortHandle,
+    ) -> TimerHandle
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.timers.insert(abort_handle.clone());
+        let _handle = crate::runtime::spawn(Abortable::new(task, registration));
+        TimerHandle::new(abort_handle)
+    }
+
+    /// Creates a function that stops the current actor instance with the provided reason.
+    ///
+    /// Unlike other ways to stop an actor, it doesn't close the mailbox, so the `Context`
+    /// can be used to run a new actor instance.
+    pub(crate) fn interrupter(&self) -> impl Fn(StopReason) + Send + Sync + 'static {
+        let address = self.weak_address();
+        move |reason| address.interrupt(reason)
+    }
+
+    /// Discards the pending interrupt and stop requests, so they won't affect the next
+    /// actor instance.
+    pub(crate) fn clear_stop_requests(&self) {
+        let _ = self.take_interrupt();
+        self.stop_requested.store(false, Ordering::Release);
+        self.terminate_requested.store(false, Ordering::Release);
+    }
 }
 }
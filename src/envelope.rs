@@ -19,33 +19,63 @@
 //! - Once `Context` processes envelope, it creates `Pin`s to both itself
 //!   and `Actor` and calls `EnvelopeProxy::handle` to process the message.
 
-use std::pin::Pin;
+use std::{any::Any, panic::AssertUnwindSafe, pin::Pin, time::Instant};
 
 use async_trait::async_trait;
+use futures::FutureExt;
 
 use crate::{
     cfg_runtime,
+    errors::SendError,
+    handler::StreamHandler,
     prelude::{Actor, Context, Handler, Notifiable},
 };
 
+/// Sending part of the channel used to report the result of the message processing.
+pub(crate) type ResponseSender<T> = async_oneshot::Sender<Result<T, SendError>>;
+
 #[async_trait]
 pub(crate) trait EnvelopeProxy<A: Actor + Unpin>: Send + 'static {
     async fn handle(&mut self, actor: Pin<&mut A>, context: Pin<&Context<A>>);
+
+    /// Extracts the message from an envelope that was not delivered to the actor.
+    fn into_message(self: Box<Self>) -> Box<dyn Any + Send>;
+
+    /// Notifies the sender (if it waits for the response) that the message won't be handled.
+    fn reject(self: Box<Self>, _error: SendError) {}
+
+    /// Returns the moment after which the sender is no longer interested in
+    /// the message processing.
+    fn deadline(&self) -> Option<Instant> {
+        None
+    }
 }
 
 pub(crate) struct MessageEnvelope<A: Handler<IN>, IN> {
-    data: Option<(IN, async_oneshot::Sender<A::Result>)>,
+    message: Option<IN>,
+    /// Response sender is kept in the envelope until the message is handled, so that
+    /// the sender can be notified if the handler is interrupted.
+    response: Option<ResponseSender<A::Result>>,
+    deadline: Option<Instant>,
 }
 
 impl<A, IN> MessageEnvelope<A, IN>
 where
     A: Handler<IN>,
 {
-    pub(crate) fn new(message: IN, response: async_oneshot::Sender<A::Result>) -> Self {
+    pub(crate) fn new(message: IN, response: ResponseSender<A::Result>) -> Self {
         Self {
-            data: Some((message, response)),
+            message: Some(message),
+            response: Some(response),
+            deadline: None,
         }
     }
+
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    pub(crate) fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
 }
 
 #[async_trait]
@@ -56,13 +86,42 @@ where
     A::Result: Send + Sync + 'static,
 {
     async fn handle(&mut self, actor: Pin<&mut A>, context: Pin<&Context<A>>) {
-        let (message, mut response) = self.data.take().expect("`Envelope::handle` called twice");
+        let message = self
+            .message
+            .take()
+            .expect("`Envelope::handle` called twice");
 
-        let result = actor
-            .get_mut()
-            .handle(message, Pin::into_inner(context))
+        let result = AssertUnwindSafe(actor.get_mut().handle(message, Pin::into_inner(context)))
+            .catch_unwind()
             .await;
-        let _ = response.send(result);
+        let mut response = self
+            .response
+            .take()
+            .expect("`Envelope::handle` called twice");
+        match result {
+            Ok(result) => {
+                let _ = response.send(Ok(result));
+            }
+            Err(panic) => {
+                // Let the sender know what happened, and let the `Context` handle the panic.
+                let _ = response.send(Err(SendError::HandlerPanicked));
+                std::panic::resume_unwind(panic);
+            }
+        }
+    }
+
+    fn into_message(self: Box<Self>) -> Box<dyn Any + Send> {
+        Box::new(self.message.expect("`Envelope::handle` called before"))
+    }
+
+    fn reject(self: Box<Self>, error: SendError) {
+        if let Some(mut response) = self.response {
+            let _ = response.send(Err(error));
+        }
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.deadline
     }
 }
 
@@ -100,20 +159,70 @@ where
             .notify(message, Pin::into_inner(context))
             .await;
     }
+
+    fn into_message(self: Box<Self>) -> Box<dyn Any + Send> {
+        Box::new(self.message.expect("`Envelope::handle` called before"))
+    }
+}
+
+/// Envelope for the events of a stream attached to an actor.
+///
+/// Unlike other envelopes, these are never sent through the mailbox.
+pub(crate) enum StreamEnvelope<A: StreamHandler<IN>, IN> {
+    Started(std::marker::PhantomData<A>),
+    Item(Option<IN>),
+    Finished,
+}
+
+impl<A, IN> StreamEnvelope<A, IN>
+where
+    A: StreamHandler<IN>,
+{
+    pub(crate) fn started() -> Self {
+        Self::Started(std::marker::PhantomData)
+    }
+
+    pub(crate) fn item(item: IN) -> Self {
+        Self::Item(Some(item))
+    }
+}
+
+#[async_trait]
+impl<A, IN> EnvelopeProxy<A> for StreamEnvelope<A, IN>
+where
+    A: StreamHandler<IN> + Actor + Send + Unpin,
+    IN: Send + 'static,
+{
+    async fn handle(&mut self, actor: Pin<&mut A>, context: Pin<&Context<A>>) {
+        let actor = actor.get_mut();
+        let context = Pin::into_inner(context);
+        match self {
+            Self::Started(_) => StreamHandler::<IN>::started(actor, context).await,
+            Self::Item(item) => {
+                let item = item.take().expect("`Envelope::handle` called twice");
+                StreamHandler::handle(actor, item, context).await;
+            }
+            Self::Finished => StreamHandler::<IN>::finished(actor, context).await,
+        }
+    }
+
+    fn into_message(self: Box<Self>) -> Box<dyn Any + Send> {
+        unreachable!("Stream envelopes are not sent through the mailbox")
+    }
 }
 
 cfg_runtime! {
     use crate::handler::Coroutine;
 
     pub(crate) struct CoroutineEnvelope<A: Coroutine<IN>, IN> {
-        data: Option<(IN, async_oneshot::Sender<A::Result>)>,
+        data: Option<(IN, ResponseSender<A::Result>)>,
     }
 
     impl<A, IN> CoroutineEnvelope<A, IN>
     where
         A: Coroutine<IN>,
     {
-        pub(crate) fn new(message: IN, response: async_oneshot::Sender<A::Result>) -> Self {
+        pub(crate) fn new(message: IN, response: ResponseSender<A::Result>) -> Self {
             Self {
                 data: Some((message, response)),
             }
@@ -135,9 +244,24 @@ cfg_runtime! {
                 .expect("`Envelope::handle` called twice");
 
             crate::runtime::spawn(async move {
-                let result = actor.calculate(message).await;
+                // Coroutine is not executed by the `Context`, so panic only affects the sender.
+                let result = AssertUnwindSafe(actor.calculate(message))
+                    .catch_unwind()
+                    .await
+                    .map_err(|_| SendError::HandlerPanicked);
                 let _ = response.send(result);
             });
         }
+
+        fn into_message(self: Box<Self>) -> Box<dyn Any + Send> {
+            let (message, _response) = self.data.expect("`Envelope::handle` called before");
+            Box::new(message)
+        }
+
+        fn reject(self: Box<Self>, error: SendError) {
+            if let Some((_message, mut response)) = self.data {
+                let _ = response.send(Err(error));
+            }
+        }
     }
 }
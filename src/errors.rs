@@ -1,16 +1,113 @@
 //! Errors that can occur during the actor interaction workflow.
 
 /// Errors that can occur while sending the message.
-#[derive(Debug)]
-pub enum SendError {
+///
+/// Type parameter `M` is the type of the message that can be returned back to
+/// the sender if it was not delivered (see [`SendError::MailboxFull`]). For the
+/// methods that do not return the message back it is `()`.
+#[derive(Debug, Clone)]
+pub enum SendError<M = ()> {
     /// Error emitted when it was attempted to send a message to the stopped actor.
     ReceiverDisconnected,
+    /// Error emitted when the mailbox of an actor is full and the message
+    /// cannot be enqueued without waiting.
+    ///
+    /// Contains the message that was attempted to be sent.
+    MailboxFull(M),
+    /// Error emitted when the response for the message was not received in time.
+    Timeout,
+    /// Error emitted when the handler of the message panicked.
+    HandlerPanicked,
+    /// Error emitted when the actor is stopping and doesn't accept new messages,
+    /// or when the message was left in the mailbox of the stopped actor.
+    ///
+    /// See [`StopMode`](crate::context::StopMode) for details.
+    ActorStopping,
 }
 
-impl std::fmt::Display for SendError {
+impl<M> std::fmt::Display for SendError<M> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Actor has disconnected and is not accepting messages.")
+        match self {
+            Self::ReceiverDisconnected => {
+                write!(f, "Actor has disconnected and is not accepting messages.")
+            }
+            Self::MailboxFull(_) => write!(f, "Actor mailbox is full."),
+            Self::Timeout => write!(f, "Actor did not respond in time."),
+            Self::HandlerPanicked => write!(f, "Actor panicked while handling the message."),
+            Self::ActorStopping => write!(f, "Actor is stopping and is not accepting messages."),
+        }
     }
 }
 
-impl std::error::Error for SendError {}
+impl<M: std::fmt::Debug> std::error::Error for SendError<M> {}
+
+/// Errors that can occur while supervising actors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupervisorError {
+    /// Supervised actors failed more often than allowed by the restart intensity,
+    /// so supervisor stopped all of them.
+    RestartIntensityExceeded,
+}
+
+impl std::fmt::Display for SupervisorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RestartIntensityExceeded => {
+                write!(f, "Supervised actors were restarted too many times.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SupervisorError {}
+
+/// Errors that can occur while retrieving services from the
+/// [`Registry`](crate::registry::Registry).
+///
+/// Type parameter `E` is the error type of the
+/// [`AsyncService`](crate::registry::AsyncService) creation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RegistryError<E = std::convert::Infallible> {
+    /// Address stored in the registry doesn't belong to the requested service.
+    TypeMismatch {
+        /// Name of the requested service.
+        name: &'static str,
+    },
+    /// Service could not be created.
+    CreationFailed(E),
+    /// Service was stopped and can't be restarted because of its
+    /// [`RestartPolicy::Never`](crate::registry::RestartPolicy::Never) policy.
+    ServiceStopped {
+        /// Name of the requested service.
+        name: &'static str,
+    },
+}
+
+#[allow(clippy::uninlined_format_args)] // Inline format args are not supported by older compilers.
+impl<E: std::fmt::Display> std::fmt::Display for RegistryError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeMismatch { name } => {
+                write!(
+                    f,
+                    "Registry contains an address of unexpected type for service {}.",
+                    name
+                )
+            }
+            Self::CreationFailed(err) => write!(f, "Unable to create the service: {}", err),
+            Self::ServiceStopped { name } => {
+                write!(f, "Service {} was stopped and can't be restarted.", name)
+            }
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for RegistryError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::TypeMismatch { .. } | Self::ServiceStopped { .. } => None,
+            Self::CreationFailed(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! [`EventBus`] delivers events to the actors that subscribed to them, so that
+//! publishers don't have to know the subscribers.
+//!
+//! Events are routed by their type: an actor implementing [`Notifiable<E>`] can subscribe
+//! to the events of type `E`, optionally with a filter, and will receive a copy of every
+//! published event of this type that passes the filter.
+//!
+//! `EventBus` is an actor implementing [`Service`], so usually it's obtained from
+//! a [`Registry`](crate::registry::Registry). Convenience methods such as
+//! [`Address::subscribe`] and [`Address::publish`] are available on its address.
+//!
+//! Bus doesn't keep the subscribers alive: once a subscriber is stopped, it's
+//! unsubscribed automatically.
+//!
+//! This module is available only when `messages` is built with one of the supported
+//! runtime features enabled, as the bus is a service.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    marker::PhantomData,
+};
+
+use async_trait::async_trait;
+use futures::future::join_all;
+
+use crate::{
+    actor::{Actor, ActorId},
+    address::{Address, WeakAddress},
+    context::Context,
+    errors::SendError,
+    handler::{Handler, Notifiable},
+    recipient::NotifyRecipient,
+    registry::Service,
+};
+
+/// Defines how [`EventBus`] treats subscribers that don't keep up with the events,
+/// i.e. subscribers with a full mailbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlowSubscriberPolicy {
+    /// Bus waits until every subscriber accepts the event.
+    ///
+    /// Subscribers are notified concurrently, but the next event is not published
+    /// until the slowest subscriber accepts the current one.
+    Block,
+    /// Event is not delivered to the subscribers with a full mailbox.
+    Drop,
+    /// Subscribers with a full mailbox are unsubscribed.
+    Unsubscribe,
+}
+
+#[allow(clippy::derivable_impls)] // `#[default]` attribute is not supported by older compilers.
+impl Default for SlowSubscriberPolicy {
+    fn default() -> Self {
+        Self::Block
+    }
+}
+
+/// Object-safe interface of a subscriber of the events of type `E`.
+trait Subscriber<E>: Send + Sync {
+    fn id(&self) -> ActorId;
+
+    fn upgrade(&self) -> Option<NotifyRecipient<E>>;
+}
+
+impl<A, E> Subscriber<E> for WeakAddress<A>
+where
+    A: Actor + Notifiable<E>,
+    E: Send + 'static,
+{
+    fn id(&self) -> ActorId {
+        WeakAddress::id(self)
+    }
+
+    fn upgrade(&self) -> Option<NotifyRecipient<E>> {
+        WeakAddress::upgrade(self).map(NotifyRecipient::from)
+    }
+}
+
+type Filter<E> = Box<dyn Fn(&E) -> bool + Send + Sync>;
+
+/// Message that subscribes an actor to the events of type `E`.
+///
+/// See [`Address::subscribe`] and [`Address::subscribe_filtered`].
+pub struct Subscribe<E> {
+    subscriber: Box<dyn Subscriber<E>>,
+    filter: Option<Filter<E>>,
+}
+
+impl<E> std::fmt::Debug for Subscribe<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscribe")
+            .field("subscriber", &self.subscriber.id())
+            .field("filtered", &self.filter.is_some())
+            .finish()
+    }
+}
+
+impl<E: Send + 'static> Subscribe<E> {
+    /// Creates a subscription of the provided actor.
+    ///
+    /// Subscription doesn't keep the actor alive.
+    #[must_use]
+    pub fn new<A>(address: &Address<A>) -> Self
+    where
+        A: Actor + Notifiable<E>,
+    {
+        Self {
+            subscriber: Box::new(address.downgrade()),
+            filter: None,
+        }
+    }
+
+    /// Sets the filter, so that only the events for which it returns `true`
+    /// are delivered to the subscriber.
+    #[must_use]
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+}
+
+/// Message that unsubscribes an actor from the events of type `E`.
+///
+/// See [`Address::unsubscribe`].
+#[derive(Debug)]
+pub struct Unsubscribe<E> {
+    id: ActorId,
+    _marker: PhantomData<fn(E)>,
+}
+
+impl<E> Unsubscribe<E> {
+    /// Creates a message that unsubscribes the actor with the provided identifier.
+    #[must_use]
+    pub fn new(id: ActorId) -> Self {
+        Self {
+            id,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Message that publishes an event to the subscribers.
+///
+/// See [`Address::publish`].
+#[derive(Debug)]
+pub struct Publish<E>(pub E);
+
+/// Message that returns the amount of the subscribers of the events of type `E`.
+///
+/// See [`Address::subscribers`].
+#[derive(Debug)]
+pub struct Subscribers<E>(PhantomData<fn(E)>);
+
+impl<E> Default for Subscribers<E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Subscribers of the events of a single type.
+struct Topic<E> {
+    subscriptions: Vec<Subscribe<E>>,
+}
+
+/// `EventBus` is a [`Service`] that delivers published events to their subscribers.
+///
+/// See the [module documentation](crate::event_bus) for details.
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// #[derive(Clone)]
+/// struct Deposit(u32);
+///
+/// struct Auditor;
+///
+/// #[async_trait]
+/// impl Actor for Auditor {}
+///
+/// #[async_trait]
+/// impl Notifiable<Deposit> for Auditor {
+///     async fn notify(&mut self, input: Deposit, context: &Context<Self>) {
+///         println!("Large deposit: {}", input.0);
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///    let registry = Registry::new();
+///    let mut bus: Address<EventBus> = registry.service().await;
+///
+///    let auditor = Auditor.spawn();
+///    bus.subscribe_filtered(&auditor, |deposit: &Deposit| deposit.0 > 1000)
+///        .await
+///        .unwrap();
+///
+///    // Only the second event is delivered to the auditor.
+///    bus.publish(Deposit(10)).await.unwrap();
+///    bus.publish(Deposit(10_000)).await.unwrap();
+///    # registry.clear().await;
+/// }
+/// ```
+#[derive(Default)]
+pub struct EventBus {
+    policy: SlowSubscriberPolicy,
+    topics: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl std::fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("policy", &self.policy)
+            .field("topics", &self.topics.len())
+            .finish()
+    }
+}
+
+impl EventBus {
+    /// Creates a new `EventBus` with the [`SlowSubscriberPolicy::Block`] policy.
+    ///
+    /// Note that in order to use a custom bus as a service, it has to be
+    /// registered via [`Registry::register`](crate::registry::Registry::register).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the policy for the subscribers that don't keep up with the events.
+    #[must_use]
+    pub fn with_policy(mut self, policy: SlowSubscriberPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    fn topic<E: Send + 'static>(&mut self) -> Option<&mut Topic<E>> {
+        self.topics
+            .get_mut(&TypeId::of::<E>())
+            .and_then(|topic| topic.downcast_mut())
+    }
+}
+
+impl Actor for EventBus {}
+
+impl Service for EventBus {
+    const NAME: &'static str = "EventBus";
+}
+
+#[async_trait]
+impl<E: Send + 'static> Handler<Subscribe<E>> for EventBus {
+    type Result = ();
+
+    async fn handle(&mut self, input: Subscribe<E>, _: &Context<Self>) {
+        let topic = self
+            .topics
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| {
+                Box::new(Topic::<E> {
+                    subscriptions: Vec::new(),
+                })
+            })
+            .downcast_mut::<Topic<E>>()
+            .expect("Topic is stored under the `TypeId` of its event");
+
+        // Repeated subscription replaces the previous one, and stopped subscribers are removed.
+        let id = input.subscriber.id();
+        topic.subscriptions.retain(|subscription| {
+            subscription.subscriber.id() != id && subscription.subscriber.upgrade().is_some()
+        });
+        topic.subscriptions.push(input);
+    }
+}
+
+#[async_trait]
+impl<E: Send + 'static> Handler<Unsubscribe<E>> for EventBus {
+    type Result = ();
+
+    async fn handle(&mut self, input: Unsubscribe<E>, _: &Context<Self>) {
+        if let Some(topic) = self.topic::<E>() {
+            topic
+                .subscriptions
+                .retain(|subscription| subscription.subscriber.id() != input.id);
+        }
+    }
+}
+
+#[async_trait]
+impl<E: Send + 'static> Handler<Subscribers<E>> for EventBus {
+    type Result = usize;
+
+    async fn handle(&mut self, _input: Subscribers<E>, _: &Context<Self>) -> usize {
+        self.topic::<E>().map_or(0, |topic| {
+            topic
+                .subscriptions
+                .retain(|subscription| subscription.subscriber.upgrade().is_some());
+            topic.subscriptions.len()
+        })
+    }
+}
+
+#[async_trait]
+impl<E: Clone + Send + 'static> Notifiable<Publish<E>> for EventBus {
+    async fn notify(&mut self, input: Publish<E>, _: &Context<Self>) {
+        let policy = self.policy;
+        if let Some(topic) = self.topic::<E>() {
+            topic.publish(input.0, policy).await;
+        }
+    }
+}
+
+impl<E: Clone + Send + 'static> Topic<E> {
+    /// Delivers the event to the subscribers and removes the ones that can't receive it.
+    async fn publish(&mut self, event: E, policy: SlowSubscriberPolicy) {
+        let mut recipients = Vec::with_capacity(self.subscriptions.len());
+        let mut unsubscribed = Vec::new();
+        for subscription in &self.subscriptions {
+            match subscription.subscriber.upgrade() {
+                Some(recipient) => {
+                    if matches!(&subscription.filter, Some(filter) if !filter(&event)) {
+                        continue;
+                    }
+                    recipients.push((subscription.subscriber.id(), recipient));
+                }
+                None => unsubscribed.push(subscription.subscriber.id()),
+            }
+        }
+
+        match policy {
+            SlowSubscriberPolicy::Block => {
+                // Events are cloned beforehand, since they are not required to be `Sync`.
+                let deliveries: Vec<_> = recipients
+                    .iter_mut()
+                    .map(|(_, recipient)| recipient.notify(event.clone()))
+                    .collect();
+                let results = join_all(deliveries).await;
+                for ((id, _), result) in recipients.iter().zip(results) {
+                    if result.is_err() {
+                        unsubscribed.push(*id);
+                    }
+                }
+            }
+            SlowSubscriberPolicy::Drop | SlowSubscriberPolicy::Unsubscribe => {
+                for (id, recipient) in &recipients {
+                    match recipient.try_notify(event.clone()) {
+                        Ok(()) => {}
+                        Err(SendError::MailboxFull(_)) if policy == SlowSubscriberPolicy::Drop => {}
+                        Err(_) => unsubscribed.push(*id),
+                    }
+                }
+            }
+        }
+
+        if !unsubscribed.is_empty() {
+            self.subscriptions
+                .retain(|subscription| !unsubscribed.contains(&subscription.subscriber.id()));
+        }
+    }
+}
+
+impl Address<EventBus> {
+    /// Subscribes the actor to the events of type `E`.
+    ///
+    /// If the actor is already subscribed to these events, its subscription is replaced.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the bus stopped working.
+    pub async fn subscribe<E, A>(&mut self, address: &Address<A>) -> Result<(), SendError>
+    where
+        E: Send + 'static,
+        A: Actor + Notifiable<E>,
+    {
+        self.send(Subscribe::new(address)).await
+    }
+
+    /// Subscribes the actor to the events of type `E` for which the filter returns `true`.
+    ///
+    /// If the actor is already subscribed to these events, its subscription is replaced.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the bus stopped working.
+    pub async fn subscribe_filtered<E, A, F>(
+        &mut self,
+        address: &Address<A>,
+        filter: F,
+    ) -> Result<(), SendError>
+    where
+        E: Send + 'static,
+        A: Actor + Notifiable<E>,
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        self.send(Subscribe::new(address).with_filter(filter)).await
+    }
+
+    /// Unsubscribes the actor from the events of type `E`.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the bus stopped working.
+    pub async fn unsubscribe<E, A>(&mut self, address: &Address<A>) -> Result<(), SendError>
+    where
+        E: Send + 'static,
+        A: Actor + Notifiable<E>,
+    {
+        self.send(Unsubscribe::<E>::new(address.id())).await
+    }
+
+    /// Publishes the event to all its subscribers.
+    ///
+    /// Method returns once the event is accepted by the bus, without waiting
+    /// for it to be delivered.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the bus stopped working.
+    pub async fn publish<E>(&mut self, event: E) -> Result<(), SendError>
+    where
+        E: Clone + Send + 'static,
+    {
+        self.notify(Publish(event)).await
+    }
+
+    /// Returns the amount of the running subscribers of the events of type `E`.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the bus stopped working.
+    pub async fn subscribers<E>(&mut self) -> Result<usize, SendError>
+    where
+        E: Send + 'static,
+    {
+        self.send(Subscribers::<E>::default()).await
+    }
+}
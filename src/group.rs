@@ -0,0 +1,299 @@
+//! Groups of type-erased addresses that receive the same message.
+//!
+//! Sending a message to several actors one by one means that each actor has to wait
+//! until the previous one accepts the message, so the whole operation is as slow as
+//! the sum of all the mailboxes. [`AddressGroup`] (for actors implementing [`Handler`])
+//! and [`Broadcast`] (for actors implementing [`Notifiable`]) send the message to all
+//! the members concurrently.
+//!
+//! Members are stored as [`Recipient`]s and [`NotifyRecipient`]s respectively, so a single
+//! group may contain actors of different types. Members that are no longer connected to
+//! their actors are removed from the group.
+//!
+//! [`Handler`]: crate::handler::Handler
+//! [`Notifiable`]: crate::handler::Notifiable
+
+use std::iter::FromIterator;
+
+use futures::future::join_all;
+
+use crate::{
+    errors::SendError,
+    recipient::{NotifyRecipient, Recipient},
+};
+
+/// `AddressGroup` is a collection of [`Recipient`]s that receive the same message.
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// struct Doubler;
+///
+/// #[async_trait]
+/// impl Actor for Doubler {}
+///
+/// #[async_trait]
+/// impl Handler<u32> for Doubler {
+///     type Result = u32;
+///
+///     async fn handle(&mut self, input: u32, context: &Context<Self>) -> u32 {
+///         input * 2
+///     }
+/// }
+///
+/// struct Squarer;
+///
+/// #[async_trait]
+/// impl Actor for Squarer {}
+///
+/// #[async_trait]
+/// impl Handler<u32> for Squarer {
+///     type Result = u32;
+///
+///     async fn handle(&mut self, input: u32, context: &Context<Self>) -> u32 {
+///         input * input
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///    let mut group: AddressGroup<u32, u32> = AddressGroup::new();
+///    group.push(Doubler.spawn());
+///    group.push(Squarer.spawn());
+///
+///    let results: Vec<u32> = group.send(3).await.into_iter().map(Result::unwrap).collect();
+///    assert_eq!(results, vec![6, 9]);
+/// }
+/// ```
+pub struct AddressGroup<IN, OUT> {
+    members: Vec<Recipient<IN, OUT>>,
+}
+
+impl<IN, OUT> std::fmt::Debug for AddressGroup<IN, OUT>
+where
+    IN: 'static,
+    OUT: 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AddressGroup")
+            .field("members", &self.members)
+            .finish()
+    }
+}
+
+impl<IN, OUT> Default for AddressGroup<IN, OUT> {
+    fn default() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+}
+
+impl<IN, OUT> Clone for AddressGroup<IN, OUT>
+where
+    IN: 'static,
+    OUT: 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            members: self.members.clone(),
+        }
+    }
+}
+
+impl<IN, OUT, R> FromIterator<R> for AddressGroup<IN, OUT>
+where
+    IN: Send + 'static,
+    OUT: Send + 'static,
+    R: Into<Recipient<IN, OUT>>,
+{
+    fn from_iter<T: IntoIterator<Item = R>>(iter: T) -> Self {
+        Self {
+            members: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<IN, OUT> AddressGroup<IN, OUT>
+where
+    IN: Send + 'static,
+    OUT: Send + 'static,
+{
+    /// Creates a new empty `AddressGroup`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a member to the group.
+    ///
+    /// Accepts either a [`Recipient`] or an [`Address`](crate::address::Address).
+    pub fn push(&mut self, recipient: impl Into<Recipient<IN, OUT>>) {
+        self.members.push(recipient.into());
+    }
+
+    /// Returns the amount of members in the group.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns `true` if the group has no members.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Removes the members that are no longer connected to their actors.
+    pub fn prune(&mut self) {
+        self.members.retain(Recipient::connected);
+    }
+
+    /// Sends a copy of the message to all the members concurrently and waits for the
+    /// responses.
+    ///
+    /// Results are returned in the order in which members were added to the group.
+    /// Members that turned out to be disconnected are removed from the group afterwards.
+    pub async fn send(&mut self, message: IN) -> Vec<Result<OUT, SendError>>
+    where
+        IN: Clone,
+    {
+        let results = join_all(
+            self.members
+                .iter_mut()
+                .map(|member| member.send(message.clone())),
+        )
+        .await;
+        self.prune();
+        results
+    }
+}
+
+/// `Broadcast` is a collection of [`NotifyRecipient`]s that receive the same notification.
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// struct Logger;
+///
+/// #[async_trait]
+/// impl Actor for Logger {}
+///
+/// #[async_trait]
+/// impl Notifiable<String> for Logger {
+///     async fn notify(&mut self, input: String, context: &Context<Self>) {
+///         println!("{}", input);
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///    let mut broadcast: Broadcast<String> = (0..3).map(|_| Logger.spawn()).collect();
+///    let results = broadcast.notify("Hello".to_string()).await;
+///    assert!(results.iter().all(Result::is_ok));
+/// }
+/// ```
+pub struct Broadcast<IN> {
+    members: Vec<NotifyRecipient<IN>>,
+}
+
+impl<IN> std::fmt::Debug for Broadcast<IN>
+where
+    IN: 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Broadcast")
+            .field("members", &self.members)
+            .finish()
+    }
+}
+
+impl<IN> Default for Broadcast<IN> {
+    fn default() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+}
+
+impl<IN> Clone for Broadcast<IN>
+where
+    IN: 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            members: self.members.clone(),
+        }
+    }
+}
+
+impl<IN, R> FromIterator<R> for Broadcast<IN>
+where
+    IN: Send + 'static,
+    R: Into<NotifyRecipient<IN>>,
+{
+    fn from_iter<T: IntoIterator<Item = R>>(iter: T) -> Self {
+        Self {
+            members: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<IN> Broadcast<IN>
+where
+    IN: Send + 'static,
+{
+    /// Creates a new empty `Broadcast`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a member to the group.
+    ///
+    /// Accepts either a [`NotifyRecipient`] or an [`Address`](crate::address::Address).
+    pub fn push(&mut self, recipient: impl Into<NotifyRecipient<IN>>) {
+        self.members.push(recipient.into());
+    }
+
+    /// Returns the amount of members in the group.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns `true` if the group has no members.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Removes the members that are no longer connected to their actors.
+    pub fn prune(&mut self) {
+        self.members.retain(NotifyRecipient::connected);
+    }
+
+    /// Sends a copy of the notification to all the members concurrently.
+    ///
+    /// Results are returned in the order in which members were added to the group.
+    /// Members that turned out to be disconnected are removed from the group afterwards.
+    pub async fn notify(&mut self, message: IN) -> Vec<Result<(), SendError>>
+    where
+        IN: Clone,
+    {
+        let results = join_all(
+            self.members
+                .iter_mut()
+                .map(|member| member.notify(message.clone())),
+        )
+        .await;
+        self.prune();
+        results
+    }
+}
@@ -6,6 +6,9 @@
 //! - [`Notifiable`]: handler for notifications, e.g. messages that do not require response.
 //! - [`Handler`]: handler that produces some data as a response to the sent message.
 //!
+//! Additionally, [`StreamHandler`] can be implemented to process items of a stream
+//! attached to an actor via [`Context::add_stream`].
+//!
 //! Note that [`Actor`] can implement both [`Notifiable`] and [`Handler`] traits in case
 //! the calculated data is important for some modules, but not so much for others.
 //!
@@ -101,6 +104,59 @@ pub trait Handler<IN>: Sized + Actor {
     async fn handle(&mut self, input: IN, context: &Context<Self>) -> Self::Result;
 }
 
+/// `StreamHandler` is an extension trait for [`Actor`] that enables it
+/// to process items of a stream attached via [`Context::add_stream`].
+///
+/// Stream is polled by the actor itself together with its mailbox, so it doesn't
+/// keep the actor alive and is dropped once the actor is stopped.
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// # use futures::stream;
+/// struct Sum(u32);
+///
+/// #[async_trait]
+/// impl Actor for Sum {}
+///
+/// #[async_trait]
+/// impl StreamHandler<u32> for Sum {
+///     async fn handle(&mut self, input: u32, context: &Context<Self>) {
+///         self.0 += input;
+///     }
+///
+///     async fn finished(&mut self, context: &Context<Self>) {
+///         println!("Sum is {}", self.0);
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///    let context = Context::new();
+///    context.add_stream(stream::iter(vec![1, 2, 3]));
+///    let mut addr = context.spawn(Sum(0));
+///    # addr.stop().await;
+///    # addr.wait_for_stop().await;
+/// }
+/// ```
+#[async_trait]
+pub trait StreamHandler<IN>: Sized + Actor {
+    /// Processes an item of the stream.
+    async fn handle(&mut self, input: IN, context: &Context<Self>);
+
+    /// Method called before the first item of the stream is processed.
+    async fn started(&mut self, _context: &Context<Self>) {}
+
+    /// Method called once the stream is exhausted.
+    ///
+    /// This method is not called if the actor was stopped before the stream
+    /// was exhausted.
+    async fn finished(&mut self, _context: &Context<Self>) {}
+}
+
 cfg_runtime! {
 
 /// Alternative to [`Handler`] that allows parallel message processing.
@@ -110,6 +110,10 @@
 //! - [`Context`](crate::prelude::Context): execution context for an actor.
 //! - [`Address`](crate::prelude::Address): address of an actor that is used to communicate with it.
 //! - Handler traits: [`Handler`](crate::prelude::Handler) and [`Notifiable`](crate::prelude::Notifiable).
+//! - Type-erased addresses: [`Recipient`](crate::prelude::Recipient) and
+//!   [`NotifyRecipient`](crate::prelude::NotifyRecipient).
+//! - Groups of type-erased addresses: [`AddressGroup`](crate::prelude::AddressGroup) and
+//!   [`Broadcast`](crate::prelude::Broadcast).
 //!
 //! With runtime features enabled, there are also several more points of interest:
 //!
@@ -117,6 +121,11 @@
 //! - [`Service`](crate::prelude::Service): Actor that can be stored in the registry.
 //! - [`Coroutine`](crate::prelude::Coroutine): Alternative to the `Handler` trait that allows
 //!   parallel message processing.
+//! - [`Pool`](crate::prelude::Pool): Group of actors with their own state that share the load.
+//! - [`EventBus`](crate::prelude::EventBus): Service that delivers published events to
+//!   their subscribers.
+//! - [`StreamHandler`](crate::prelude::StreamHandler): Trait for processing items of a stream
+//!   attached to an actor.
 //!
 
 /// Collection of the main types required to work with `messages` crate.
@@ -125,17 +134,24 @@ pub mod prelude {
     pub use async_trait::async_trait;
 
     pub use crate::{
-        actor::{Actor, ActorAction},
-        address::Address,
+        actor::{Actor, ActorAction, ActorId, PanicAction, RunOutcome, StopReason, Terminated},
+        address::{Address, WeakAddress},
         context::Context,
         errors::SendError,
-        handler::{Handler, Notifiable},
+        group::{AddressGroup, Broadcast},
+        handler::{Handler, Notifiable, StreamHandler},
+        recipient::{NotifyRecipient, Recipient},
     };
 
     super::cfg_runtime! {
-        pub use crate::registry::{Service, Registry};
-        pub use crate::actor::RuntimeActorExt;
+        pub use crate::event_bus::{EventBus, SlowSubscriberPolicy};
+        pub use crate::pool::{Pool, Router};
+        pub use crate::registry::{AsyncService, RestartPolicy, Service, Registry};
+        pub use crate::supervisor::{RestartStrategy, Supervisor};
+        pub use crate::system::ActorSystem;
+        pub use crate::actor::{ActorJoinHandle, RuntimeActorExt};
         pub use crate::handler::Coroutine;
+        pub use crate::timer::TimerHandle;
 
         /// Re-export of `JoinHandle` of chosen runtime.
         #[cfg_attr(not(docsrs), doc(hidden))]
@@ -150,10 +166,18 @@ pub mod actor;
 pub mod address;
 pub mod context;
 pub mod errors;
+pub mod group;
 pub mod handler;
+pub mod mailbox;
+pub mod recipient;
+pub mod timer;
 
 cfg_runtime! {
+    pub mod event_bus;
+    pub mod pool;
     pub mod registry;
+    pub mod supervisor;
+    pub mod system;
 }
 
 mod envelope;
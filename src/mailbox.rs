@@ -0,0 +1,388 @@
+//! Mailbox is a queue of incoming messages of an actor.
+//!
+//! By default, mailbox has a limited capacity, and senders have to wait until
+//! there is a free space in it. This behavior can be changed via [`MailboxConfig`]:
+//! for example, actors processing high-rate telemetry may prefer to shed the load
+//! instead of slowing producers down.
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
+
+use async_channel::TrySendError;
+use futures::StreamExt;
+
+use crate::{
+    actor::Actor,
+    context::{InputHandle, Signal, DEFAULT_CAPACITY},
+    errors::SendError,
+};
+
+/// Behavior of the mailbox when a message is sent to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MailboxPolicy {
+    /// Sender waits until there is a free space in the mailbox.
+    Block,
+    /// Mailbox has no capacity limit, so sender never waits.
+    Unbounded,
+    /// If the mailbox is full, the message being sent is dropped.
+    DropNewest,
+    /// If the mailbox is full, the oldest message in the mailbox is dropped
+    /// to make room for the message being sent.
+    ///
+    /// Sender of the dropped message (if it waits for the response) receives
+    /// [`SendError::MailboxFull`].
+    DropOldest,
+    /// If the mailbox is full, the message is rejected and the sender
+    /// receives [`SendError::MailboxFull`].
+    Reject,
+}
+
+/// Configuration of an actor mailbox.
+///
+/// ## Examples
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// use messages::mailbox::{MailboxConfig, MailboxPolicy};
+///
+/// struct Telemetry;
+///
+/// #[async_trait]
+/// impl Actor for Telemetry {}
+///
+/// let config = MailboxConfig::new(1024).with_policy(MailboxPolicy::DropOldest);
+/// let context: Context<Telemetry> = Context::with_config(config);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MailboxConfig {
+    capacity: usize,
+    policy: MailboxPolicy,
+}
+
+impl Default for MailboxConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl MailboxConfig {
+    /// Creates a configuration for the mailbox with the provided capacity
+    /// and [`MailboxPolicy::Block`] policy.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the capacity is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "Mailbox capacity must be positive");
+
+        Self {
+            capacity,
+            policy: MailboxPolicy::Block,
+        }
+    }
+
+    /// Creates a configuration for the mailbox without capacity limit.
+    #[must_use]
+    pub fn unbounded() -> Self {
+        Self::default().with_policy(MailboxPolicy::Unbounded)
+    }
+
+    /// Sets the policy of the mailbox.
+    #[must_use]
+    pub fn with_policy(mut self, policy: MailboxPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the capacity of the mailbox.
+    ///
+    /// Capacity is ignored for [`MailboxPolicy::Unbounded`].
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the policy of the mailbox.
+    #[must_use]
+    pub fn policy(&self) -> MailboxPolicy {
+        self.policy
+    }
+}
+
+/// Statistics of messages that were not delivered to an actor because
+/// of the mailbox policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MailboxStats {
+    /// Amount of messages dropped due to [`MailboxPolicy::DropNewest`] or
+    /// [`MailboxPolicy::DropOldest`] policy.
+    pub dropped: u64,
+    /// Amount of messages rejected due to [`MailboxPolicy::Reject`] policy.
+    pub rejected: u64,
+}
+
+#[derive(Debug, Default)]
+struct MailboxCounters {
+    dropped: AtomicU64,
+    rejected: AtomicU64,
+}
+
+/// Errors that can occur while putting a message into the mailbox.
+///
+/// Each variant contains the message that was not delivered.
+#[derive(Debug)]
+pub(crate) enum MailboxError<T> {
+    /// Message was dropped according to the [`MailboxPolicy::DropNewest`] policy.
+    Dropped(T),
+    /// Mailbox is full and the message cannot be enqueued without waiting.
+    Full(T),
+    /// Actor is not receiving messages anymore.
+    Closed(T),
+    /// Actor is stopping and rejects new messages.
+    Stopping(T),
+}
+
+/// Creates a mailbox according to the provided configuration.
+pub(crate) fn mailbox<A>(config: MailboxConfig) -> (MailboxSender<A>, MailboxReceiver<A>) {
+    let (sender, receiver) = match config.policy {
+        MailboxPolicy::Unbounded => async_channel::unbounded(),
+        _ => async_channel::bounded(config.capacity),
+    };
+    // System lane is not affected by the mailbox policy, so it's always unbounded.
+    let (priority_sender, priority_receiver) = async_channel::unbounded();
+
+    // In order to drop the oldest message, we need to be able to receive it.
+    let oldest_receiver = match config.policy {
+        MailboxPolicy::DropOldest => Some(receiver.clone()),
+        _ => None,
+    };
+
+    let stopping = Arc::new(AtomicBool::new(false));
+
+    let sender = MailboxSender {
+        sender,
+        priority_sender,
+        oldest_receiver,
+        policy: config.policy,
+        counters: Arc::default(),
+        stopping: stopping.clone(),
+    };
+    let receiver = MailboxReceiver {
+        receiver,
+        priority_receiver,
+        stopping,
+    };
+    (sender, receiver)
+}
+
+/// Receiving part of the mailbox.
+///
+/// Mailbox consists of two lanes: the normal one, and the high-priority (system) one.
+/// System lane is used for control signals (e.g. stop requests) and priority messages,
+/// and it's always drained before the normal lane.
+pub(crate) struct MailboxReceiver<A> {
+    receiver: async_channel::Receiver<Signal<InputHandle<A>>>,
+    priority_receiver: async_channel::Receiver<Signal<InputHandle<A>>>,
+    /// Set while the mailbox is closed, but the queued messages are still being handled.
+    stopping: Arc<AtomicBool>,
+}
+
+impl<A> MailboxReceiver<A> {
+    /// Receives the next signal, preferring the system lane.
+    ///
+    /// Returns `None` once the mailbox is closed and there are no more messages in it.
+    pub(crate) async fn recv(&mut self) -> Option<Signal<InputHandle<A>>> {
+        futures::select_biased! {
+            signal = self.priority_receiver.next() => match signal {
+                Some(signal) => Some(signal),
+                // System lane is closed, but there may be messages left in the normal one.
+                None => self.receiver.next().await,
+            },
+            signal = self.receiver.next() => signal,
+            complete => None,
+        }
+    }
+
+    /// Receives the next signal without waiting, preferring the system lane.
+    pub(crate) fn try_recv(&self) -> Option<Signal<InputHandle<A>>> {
+        self.priority_receiver
+            .try_recv()
+            .or_else(|_| self.receiver.try_recv())
+            .ok()
+    }
+
+    /// Closes both lanes of the mailbox, so senders are notified that the actor
+    /// is stopping, while the messages that are already queued can still be received.
+    pub(crate) fn close_for_stop(&self) {
+        self.stopping.store(true, Ordering::Release);
+        self.close_lanes();
+    }
+
+    /// Closes both lanes of the mailbox, so senders are notified that the actor
+    /// is disconnected.
+    pub(crate) fn close(&self) {
+        self.stopping.store(false, Ordering::Release);
+        self.close_lanes();
+    }
+
+    fn close_lanes(&self) {
+        self.receiver.close();
+        self.priority_receiver.close();
+    }
+}
+
+/// Sending part of the mailbox that applies the configured [`MailboxPolicy`].
+pub(crate) struct MailboxSender<A> {
+    sender: async_channel::Sender<Signal<InputHandle<A>>>,
+    priority_sender: async_channel::Sender<Signal<InputHandle<A>>>,
+    oldest_receiver: Option<async_channel::Receiver<Signal<InputHandle<A>>>>,
+    policy: MailboxPolicy,
+    counters: Arc<MailboxCounters>,
+    stopping: Arc<AtomicBool>,
+}
+
+impl<A> Clone for MailboxSender<A> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            priority_sender: self.priority_sender.clone(),
+            oldest_receiver: self.oldest_receiver.clone(),
+            policy: self.policy,
+            counters: self.counters.clone(),
+            stopping: self.stopping.clone(),
+        }
+    }
+}
+
+impl<A> MailboxSender<A> {
+    /// Puts a message into the mailbox, waiting for a free space if required by the policy.
+    pub(crate) async fn send(
+        &self,
+        message: InputHandle<A>,
+    ) -> Result<(), MailboxError<InputHandle<A>>>
+    where
+        A: Actor,
+    {
+        if self.policy == MailboxPolicy::Block {
+            return self
+                .sender
+                .send(Signal::Message(message))
+                .await
+                .map_err(|err| self.closed(Self::unwrap_message(err.into_inner())));
+        }
+
+        self.try_send(message)
+    }
+
+    /// Puts a message into the mailbox without waiting.
+    pub(crate) fn try_send(
+        &self,
+        message: InputHandle<A>,
+    ) -> Result<(), MailboxError<InputHandle<A>>>
+    where
+        A: Actor,
+    {
+        let mut signal = Signal::Message(message);
+        loop {
+            signal = match self.sender.try_send(signal) {
+                Ok(()) => return Ok(()),
+                Err(TrySendError::Closed(signal)) => {
+                    return Err(self.closed(Self::unwrap_message(signal)))
+                }
+                Err(TrySendError::Full(signal)) => signal,
+            };
+
+            let message = match self.policy {
+                MailboxPolicy::DropOldest => {
+                    if let Some(receiver) = &self.oldest_receiver {
+                        if let Ok(evicted) = receiver.try_recv() {
+                            self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+                            // Let the sender of the evicted message know that it was shed
+                            // because of the load rather than because the actor is stopped.
+                            if let Signal::Message(envelope) = evicted {
+                                envelope.reject(SendError::MailboxFull(()));
+                            }
+                        }
+                    }
+                    // Try again with the freed space.
+                    continue;
+                }
+                _ => Self::unwrap_message(signal),
+            };
+
+            return Err(match self.policy {
+                MailboxPolicy::DropNewest => {
+                    self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+                    MailboxError::Dropped(message)
+                }
+                MailboxPolicy::Reject => {
+                    self.counters.rejected.fetch_add(1, Ordering::Relaxed);
+                    MailboxError::Full(message)
+                }
+                // `Block` policy can only end up here for non-blocking send.
+                // `Unbounded` mailbox is never full.
+                _ => MailboxError::Full(message),
+            });
+        }
+    }
+
+    /// Puts a signal into the system lane of the mailbox.
+    ///
+    /// System lane is unbounded and is not affected by the mailbox policy.
+    pub(crate) fn send_priority(&self, signal: Signal<InputHandle<A>>) -> Result<(), SendError> {
+        self.priority_sender.try_send(signal).map_err(|_| {
+            if self.is_stopping() {
+                SendError::ActorStopping
+            } else {
+                SendError::ReceiverDisconnected
+            }
+        })
+    }
+
+    /// Returns statistics of the messages that were dropped or rejected.
+    pub(crate) fn stats(&self) -> MailboxStats {
+        MailboxStats {
+            dropped: self.counters.dropped.load(Ordering::Relaxed),
+            rejected: self.counters.rejected.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns the amount of messages in the mailbox.
+    pub(crate) fn len(&self) -> usize {
+        self.sender.len()
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
+    /// Returns `true` if the actor is stopping and handles the messages left in the mailbox.
+    fn is_stopping(&self) -> bool {
+        self.stopping.load(Ordering::Acquire)
+    }
+
+    /// Creates an error for the message that was sent to the closed mailbox.
+    fn closed<T>(&self, message: T) -> MailboxError<T> {
+        if self.is_stopping() {
+            MailboxError::Stopping(message)
+        } else {
+            MailboxError::Closed(message)
+        }
+    }
+
+    pub(crate) fn close(&self) {
+        self.sender.close();
+        self.priority_sender.close();
+    }
+
+    fn unwrap_message(signal: Signal<InputHandle<A>>) -> InputHandle<A> {
+        match signal {
+            Signal::Message(message) => message,
+            Signal::Stop | Signal::Interrupt => {
+                unreachable!("Only messages are sent through `MailboxSender::send`")
+            }
+        }
+    }
+}
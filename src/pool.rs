@@ -0,0 +1,368 @@
+//! [`Pool`] spreads messages over a group of identical actors.
+//!
+//! Unlike [`Coroutine`](crate::prelude::Coroutine), which processes messages in parallel
+//! using clones of a single actor, each actor in a pool has its own state and mailbox.
+//! The actor that receives a message is chosen by the [`Router`] of the pool.
+//!
+//! This module is available only when `messages` is built with one of the supported
+//! runtime features enabled, as it needs to spawn actors.
+
+use std::{
+    collections::hash_map::{DefaultHasher, RandomState},
+    hash::{BuildHasher, Hash, Hasher},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::{
+    actor::Actor,
+    address::Address,
+    context::Context,
+    errors::SendError,
+    handler::{Handler, Notifiable},
+};
+
+/// Amount of points each actor occupies on the hash ring used by [`Router::ConsistentHash`].
+const VIRTUAL_NODES: usize = 64;
+
+/// Defines which actor of the [`Pool`] receives a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Router {
+    /// Actors receive messages in turn.
+    RoundRobin,
+    /// Message is sent to a randomly chosen actor.
+    Random,
+    /// Message is sent to the actor with the least amount of messages in its mailbox.
+    ShortestMailbox,
+    /// Message is sent to the actor chosen by the key provided via [`Pool::send_by_key`]
+    /// or [`Pool::notify_by_key`], so messages with the same key are processed by the same
+    /// actor. Once the pool is resized, only a part of the keys is moved to the other actors.
+    ///
+    /// Messages sent without a key are routed in a round-robin manner.
+    ConsistentHash,
+}
+
+#[allow(clippy::derivable_impls)] // `#[default]` attribute is not supported by older compilers.
+impl Default for Router {
+    fn default() -> Self {
+        Self::RoundRobin
+    }
+}
+
+type Factory<A> = Box<dyn FnMut(&Context<A>) -> A + Send>;
+
+/// `Pool` is a group of actors created by the same factory, which can be used to send
+/// messages to one of them, similarly to an [`Address`].
+///
+/// Messages are sent via a shared reference, so several messages can be processed
+/// by the actors of the pool at the same time. Clones of a pool share its actors, and
+/// the actors are stopped once all the clones are dropped, after processing the messages
+/// that are already in their mailboxes.
+///
+/// Actors that stopped (e.g. because of a panic) are replaced with new ones created
+/// by the factory once the router chooses them to receive a message.
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// struct Counter(u32);
+///
+/// #[async_trait]
+/// impl Actor for Counter {}
+///
+/// #[async_trait]
+/// impl Handler<u32> for Counter {
+///     type Result = u32;
+///
+///     async fn handle(&mut self, input: u32, context: &Context<Self>) -> u32 {
+///         self.0 += input;
+///         self.0
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let pool = Pool::new(4, |_context| Counter(0)).with_router(Router::ConsistentHash);
+///     // Messages with the same key are handled by the same actor.
+///     assert_eq!(pool.send_by_key(&"user", 1).await.unwrap(), 1);
+///     assert_eq!(pool.send_by_key(&"user", 1).await.unwrap(), 2);
+///
+///     pool.resize(2);
+///     assert_eq!(pool.size(), 2);
+/// }
+/// ```
+pub struct Pool<A> {
+    router: Router,
+    members: Arc<Mutex<Members<A>>>,
+}
+
+/// State of the pool shared by its clones.
+///
+/// Lock is never held across an `.await`, only while the actor is chosen.
+struct Members<A> {
+    factory: Factory<A>,
+    addresses: Vec<Address<A>>,
+    /// Points of the hash ring sorted by their hash, with indices of corresponding actors.
+    ring: Vec<(u64, usize)>,
+    next: usize,
+    rng: u64,
+}
+
+impl<A> Clone for Pool<A> {
+    fn clone(&self) -> Self {
+        Self {
+            router: self.router,
+            members: Arc::clone(&self.members),
+        }
+    }
+}
+
+impl<A> std::fmt::Debug for Pool<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pool")
+            .field("router", &self.router)
+            .field("addresses", &self.lock().addresses)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A> Pool<A> {
+    fn lock(&self) -> MutexGuard<'_, Members<A>> {
+        self.members.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<A> Pool<A>
+where
+    A: Actor + Send + Unpin + 'static,
+{
+    /// Creates a pool of `size` actors created by the provided factory.
+    ///
+    /// Factory is invoked every time a new actor is added to the pool.
+    /// By default, [`Router::RoundRobin`] is used.
+    pub fn new<F>(size: usize, factory: F) -> Self
+    where
+        F: FnMut(&Context<A>) -> A + Send + 'static,
+    {
+        let pool = Self {
+            router: Router::default(),
+            members: Arc::new(Mutex::new(Members {
+                factory: Box::new(factory),
+                addresses: Vec::with_capacity(size),
+                ring: Vec::new(),
+                next: 0,
+                // Seed doesn't have to be cryptographically secure, it only has to differ
+                // between pools.
+                rng: RandomState::new().build_hasher().finish() | 1,
+            })),
+        };
+        pool.resize(size);
+        pool
+    }
+
+    /// Sets the router of the pool.
+    ///
+    /// Router is not shared with the existing clones of the pool.
+    #[must_use]
+    pub fn with_router(mut self, router: Router) -> Self {
+        self.router = router;
+        self
+    }
+
+    /// Returns the router of the pool.
+    #[must_use]
+    pub fn router(&self) -> Router {
+        self.router
+    }
+
+    /// Returns the amount of actors in the pool.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.lock().addresses.len()
+    }
+
+    /// Returns the addresses of the actors in the pool.
+    #[must_use]
+    pub fn addresses(&self) -> Vec<Address<A>> {
+        self.lock().addresses.clone()
+    }
+
+    /// Changes the amount of actors in the pool.
+    ///
+    /// New actors are created by the factory of the pool. If the pool shrinks,
+    /// the most recently added actors are removed from it: they stop once they
+    /// process the messages that are already in their mailboxes (unless there
+    /// are other addresses of these actors).
+    pub fn resize(&self, size: usize) {
+        let mut members = self.lock();
+        members.addresses.truncate(size);
+        while members.addresses.len() < size {
+            let address = members.spawn();
+            members.addresses.push(address);
+        }
+
+        members.ring = (0..size)
+            .flat_map(|index| (0..VIRTUAL_NODES).map(move |node| (hash(&(index, node)), index)))
+            .collect();
+        members.ring.sort_unstable();
+    }
+
+    /// Sends a message to one of the actors chosen by the router and waits for the result.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the chosen actor stopped working
+    /// or the pool is empty.
+    pub async fn send<IN>(&self, message: IN) -> Result<A::Result, SendError>
+    where
+        A: Handler<IN>,
+        IN: Send + 'static,
+        A::Result: Send + Sync + 'static,
+    {
+        match self.route(None) {
+            Some(mut address) => address.send(message).await,
+            None => Err(SendError::ReceiverDisconnected),
+        }
+    }
+
+    /// Sends a notification to one of the actors chosen by the router.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the chosen actor stopped working
+    /// or the pool is empty.
+    pub async fn notify<IN>(&self, message: IN) -> Result<(), SendError>
+    where
+        A: Notifiable<IN>,
+        IN: Send + 'static,
+    {
+        match self.route(None) {
+            Some(mut address) => address.notify(message).await,
+            None => Err(SendError::ReceiverDisconnected),
+        }
+    }
+
+    /// Version of [`Pool::send`] that routes the message by the provided key
+    /// if [`Router::ConsistentHash`] is used. Other routers ignore the key.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the chosen actor stopped working
+    /// or the pool is empty.
+    pub async fn send_by_key<K, IN>(&self, key: &K, message: IN) -> Result<A::Result, SendError>
+    where
+        K: Hash + ?Sized,
+        A: Handler<IN>,
+        IN: Send + 'static,
+        A::Result: Send + Sync + 'static,
+    {
+        match self.route(Some(hash(key))) {
+            Some(mut address) => address.send(message).await,
+            None => Err(SendError::ReceiverDisconnected),
+        }
+    }
+
+    /// Version of [`Pool::notify`] that routes the message by the provided key
+    /// if [`Router::ConsistentHash`] is used. Other routers ignore the key.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case the chosen actor stopped working
+    /// or the pool is empty.
+    pub async fn notify_by_key<K, IN>(&self, key: &K, message: IN) -> Result<(), SendError>
+    where
+        K: Hash + ?Sized,
+        A: Notifiable<IN>,
+        IN: Send + 'static,
+    {
+        match self.route(Some(hash(key))) {
+            Some(mut address) => address.notify(message).await,
+            None => Err(SendError::ReceiverDisconnected),
+        }
+    }
+
+    /// Stops all the actors in the pool and waits until they are stopped.
+    ///
+    /// Pool stays empty until it's resized.
+    pub async fn stop(&self) {
+        let addresses = {
+            let mut members = self.lock();
+            members.ring.clear();
+            std::mem::take(&mut members.addresses)
+        };
+        for mut address in addresses {
+            address.stop().await;
+            address.wait_for_stop().await;
+        }
+    }
+
+    /// Chooses the actor that should receive the message.
+    ///
+    /// Address is cloned, so the lock is released before the message is sent.
+    fn route(&self, key: Option<u64>) -> Option<Address<A>> {
+        let mut members = self.lock();
+        let size = members.addresses.len();
+        if size == 0 {
+            return None;
+        }
+
+        let index = match (self.router, key) {
+            (Router::Random, _) => {
+                // xorshift64 is good enough to spread the messages.
+                members.rng ^= members.rng << 13;
+                members.rng ^= members.rng >> 7;
+                members.rng ^= members.rng << 17;
+                // Remainder is less than `size`, so it fits into `usize`.
+                #[allow(clippy::cast_possible_truncation)]
+                let index = (members.rng % size as u64) as usize;
+                index
+            }
+            (Router::ShortestMailbox, _) => {
+                // Start from the next actor in turn, so ties are resolved in a round-robin manner.
+                let start = members.next_index();
+                (start..start + size)
+                    .map(|index| index % size)
+                    .min_by_key(|&index| members.addresses[index].mailbox_len())
+                    .unwrap_or(start)
+            }
+            (Router::ConsistentHash, Some(key)) => {
+                let point = match members.ring.binary_search_by_key(&key, |&(point, _)| point) {
+                    Ok(point) | Err(point) => point % members.ring.len(),
+                };
+                members.ring[point].1
+            }
+            (Router::RoundRobin | Router::ConsistentHash, _) => members.next_index(),
+        };
+
+        // Stopped actor is replaced in place, so the keys it owned are not moved.
+        if !members.addresses[index].connected() {
+            members.addresses[index] = members.spawn();
+        }
+        Some(members.addresses[index].clone())
+    }
+}
+
+impl<A> Members<A>
+where
+    A: Actor + Send + Unpin + 'static,
+{
+    fn spawn(&mut self) -> Address<A> {
+        let context = Context::new();
+        let actor = (self.factory)(&context);
+        context.spawn(actor)
+    }
+
+    fn next_index(&mut self) -> usize {
+        let index = self.next % self.addresses.len();
+        self.next = index + 1;
+        index
+    }
+}
+
+fn hash<K: Hash + ?Sized>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
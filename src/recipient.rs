@@ -0,0 +1,417 @@
+//! Type-erased addresses capable of sending messages of a single type.
+//!
+//! [`Address`] is tied to the type of an [`Actor`], which is not always convenient:
+//! component that only needs to send a certain message has to know the concrete actor
+//! type (or be generic over it).
+//!
+//! [`Recipient`] (for actors implementing [`Handler`]) and [`NotifyRecipient`] (for actors
+//! implementing [`Notifiable`]) hide the actor type, so e.g. addresses of different actors
+//! can be stored in the same collection.
+
+use async_trait::async_trait;
+
+use crate::{
+    actor::{Actor, ActorId, StopReason},
+    address::Address,
+    errors::SendError,
+    handler::{Handler, Notifiable},
+};
+
+/// Object-safe part of the [`Address`] interface used by [`Recipient`].
+#[async_trait]
+trait MessageSender<IN, OUT>: Send + Sync + 'static {
+    async fn send(&mut self, message: IN) -> Result<OUT, SendError>;
+
+    async fn stop(&mut self);
+
+    async fn wait_for_stop(&self) -> StopReason;
+
+    fn connected(&self) -> bool;
+
+    fn id(&self) -> ActorId;
+
+    fn name(&self) -> Option<&str>;
+
+    fn clone_box(&self) -> Box<dyn MessageSender<IN, OUT>>;
+}
+
+#[async_trait]
+impl<A, IN> MessageSender<IN, A::Result> for Address<A>
+where
+    A: Actor + Send + Handler<IN> + 'static,
+    IN: Send + 'static,
+    A::Result: Send + Sync + 'static,
+{
+    async fn send(&mut self, message: IN) -> Result<A::Result, SendError> {
+        Address::send(self, message).await
+    }
+
+    async fn stop(&mut self) {
+        Address::stop(self).await;
+    }
+
+    async fn wait_for_stop(&self) -> StopReason {
+        Address::wait_for_stop(self).await
+    }
+
+    fn connected(&self) -> bool {
+        Address::connected(self)
+    }
+
+    fn id(&self) -> ActorId {
+        Address::id(self)
+    }
+
+    fn name(&self) -> Option<&str> {
+        Address::name(self)
+    }
+
+    fn clone_box(&self) -> Box<dyn MessageSender<IN, A::Result>> {
+        Box::new(self.clone())
+    }
+}
+
+/// Object-safe part of the [`Address`] interface used by [`NotifyRecipient`].
+#[async_trait]
+trait NotificationSender<IN>: Send + Sync + 'static {
+    async fn notify(&mut self, message: IN) -> Result<(), SendError>;
+
+    fn try_notify(&self, message: IN) -> Result<(), SendError<IN>>;
+
+    async fn stop(&mut self);
+
+    async fn wait_for_stop(&self) -> StopReason;
+
+    fn connected(&self) -> bool;
+
+    fn id(&self) -> ActorId;
+
+    fn name(&self) -> Option<&str>;
+
+    fn clone_box(&self) -> Box<dyn NotificationSender<IN>>;
+}
+
+#[async_trait]
+impl<A, IN> NotificationSender<IN> for Address<A>
+where
+    A: Actor + Send + Notifiable<IN> + 'static,
+    IN: Send + 'static,
+{
+    async fn notify(&mut self, message: IN) -> Result<(), SendError> {
+        Address::notify(self, message).await
+    }
+
+    fn try_notify(&self, message: IN) -> Result<(), SendError<IN>> {
+        Address::try_notify(self, message)
+    }
+
+    async fn stop(&mut self) {
+        Address::stop(self).await;
+    }
+
+    async fn wait_for_stop(&self) -> StopReason {
+        Address::wait_for_stop(self).await
+    }
+
+    fn connected(&self) -> bool {
+        Address::connected(self)
+    }
+
+    fn id(&self) -> ActorId {
+        Address::id(self)
+    }
+
+    fn name(&self) -> Option<&str> {
+        Address::name(self)
+    }
+
+    fn clone_box(&self) -> Box<dyn NotificationSender<IN>> {
+        Box::new(self.clone())
+    }
+}
+
+/// `Recipient` is a type-erased [`Address`] that can only be used to send
+/// messages of type `IN` and receive responses of type `OUT`.
+///
+/// `Recipient` can be obtained via [`Address::recipient`].
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// struct Doubler;
+///
+/// #[async_trait]
+/// impl Actor for Doubler {}
+///
+/// #[async_trait]
+/// impl Handler<u32> for Doubler {
+///     type Result = u32;
+///
+///     async fn handle(&mut self, input: u32, context: &Context<Self>) -> u32 {
+///         input * 2
+///     }
+/// }
+///
+/// struct Squarer;
+///
+/// #[async_trait]
+/// impl Actor for Squarer {}
+///
+/// #[async_trait]
+/// impl Handler<u32> for Squarer {
+///     type Result = u32;
+///
+///     async fn handle(&mut self, input: u32, context: &Context<Self>) -> u32 {
+///         input * input
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///    let mut recipients: Vec<Recipient<u32, u32>> = vec![
+///        Doubler.spawn().recipient(),
+///        Squarer.spawn().recipient(),
+///    ];
+///
+///    let mut results = Vec::new();
+///    for recipient in &mut recipients {
+///        results.push(recipient.send(3).await.unwrap());
+///    }
+///    assert_eq!(results, vec![6, 9]);
+///    # for recipient in &mut recipients {
+///    #     recipient.stop().await;
+///    #     recipient.wait_for_stop().await;
+///    # }
+/// }
+/// ```
+pub struct Recipient<IN, OUT> {
+    inner: Box<dyn MessageSender<IN, OUT>>,
+}
+
+impl<IN, OUT> std::fmt::Debug for Recipient<IN, OUT>
+where
+    IN: 'static,
+    OUT: 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recipient")
+            .field("id", &self.inner.id())
+            .field("name", &self.inner.name())
+            .field("connected", &self.inner.connected())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<IN, OUT> Clone for Recipient<IN, OUT>
+where
+    IN: 'static,
+    OUT: 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone_box(),
+        }
+    }
+}
+
+impl<A, IN> From<Address<A>> for Recipient<IN, A::Result>
+where
+    A: Actor + Send + Handler<IN> + 'static,
+    IN: Send + 'static,
+    A::Result: Send + Sync + 'static,
+{
+    fn from(address: Address<A>) -> Self {
+        Self {
+            inner: Box::new(address),
+        }
+    }
+}
+
+impl<IN, OUT> Recipient<IN, OUT>
+where
+    IN: Send + 'static,
+    OUT: Send + 'static,
+{
+    /// Sends a message to the [`Actor`] and receives the response.
+    ///
+    /// See [`Address::send`] for details.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case associated actor stopped working.
+    pub async fn send(&mut self, message: IN) -> Result<OUT, SendError> {
+        self.inner.send(message).await
+    }
+
+    /// Returns `true` if `Recipient` is still connected to the [`Actor`].
+    #[must_use]
+    pub fn connected(&self) -> bool {
+        self.inner.connected()
+    }
+
+    /// Returns the unique identifier of the [`Actor`].
+    #[must_use]
+    pub fn id(&self) -> ActorId {
+        self.inner.id()
+    }
+
+    /// Returns the name of the [`Actor`], if it was set.
+    ///
+    /// See [`Address::name`] for details.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.inner.name()
+    }
+
+    /// Sends a stop request to the corresponding [`Actor`].
+    ///
+    /// See [`Address::stop`] for details.
+    pub async fn stop(&mut self) {
+        self.inner.stop().await;
+    }
+
+    /// Creates a future that waits for actor to be fully stopped.
+    ///
+    /// See [`Address::wait_for_stop`] for details.
+    pub async fn wait_for_stop(&self) -> StopReason {
+        self.inner.wait_for_stop().await
+    }
+}
+
+/// `NotifyRecipient` is a type-erased [`Address`] that can only be used to send
+/// notifications of type `IN`.
+///
+/// `NotifyRecipient` can be obtained via [`Address::notify_recipient`].
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// struct Logger;
+///
+/// #[async_trait]
+/// impl Actor for Logger {}
+///
+/// #[async_trait]
+/// impl Notifiable<String> for Logger {
+///     async fn notify(&mut self, input: String, context: &Context<Self>) {
+///         println!("{}", input);
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///    let mut recipient: NotifyRecipient<String> = Logger.spawn().notify_recipient();
+///    recipient.notify("Hello".to_string()).await.unwrap();
+///    # recipient.stop().await;
+///    # recipient.wait_for_stop().await;
+/// }
+/// ```
+pub struct NotifyRecipient<IN> {
+    inner: Box<dyn NotificationSender<IN>>,
+}
+
+impl<IN> std::fmt::Debug for NotifyRecipient<IN>
+where
+    IN: 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NotifyRecipient")
+            .field("id", &self.inner.id())
+            .field("name", &self.inner.name())
+            .field("connected", &self.inner.connected())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<IN> Clone for NotifyRecipient<IN>
+where
+    IN: 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone_box(),
+        }
+    }
+}
+
+impl<A, IN> From<Address<A>> for NotifyRecipient<IN>
+where
+    A: Actor + Send + Notifiable<IN> + 'static,
+    IN: Send + 'static,
+{
+    fn from(address: Address<A>) -> Self {
+        Self {
+            inner: Box::new(address),
+        }
+    }
+}
+
+impl<IN> NotifyRecipient<IN>
+where
+    IN: Send + 'static,
+{
+    /// Sends a notification to the [`Actor`] without receiving any kind of response.
+    ///
+    /// See [`Address::notify`] for details.
+    ///
+    /// ## Errors
+    ///
+    /// Will return an error in case associated actor stopped working.
+    pub async fn notify(&mut self, message: IN) -> Result<(), SendError> {
+        self.inner.notify(message).await
+    }
+
+    /// Attempts to send a notification to the [`Actor`] without waiting for the free
+    /// space in its mailbox.
+    ///
+    /// See [`Address::try_notify`] for details.
+    ///
+    /// ## Errors
+    ///
+    /// Will return [`SendError::MailboxFull`] with the message if the mailbox of
+    /// the actor is full, or [`SendError::ReceiverDisconnected`] if associated actor
+    /// stopped working.
+    pub fn try_notify(&self, message: IN) -> Result<(), SendError<IN>> {
+        self.inner.try_notify(message)
+    }
+
+    /// Returns `true` if `NotifyRecipient` is still connected to the [`Actor`].
+    #[must_use]
+    pub fn connected(&self) -> bool {
+        self.inner.connected()
+    }
+
+    /// Returns the unique identifier of the [`Actor`].
+    #[must_use]
+    pub fn id(&self) -> ActorId {
+        self.inner.id()
+    }
+
+    /// Returns the name of the [`Actor`], if it was set.
+    ///
+    /// See [`Address::name`] for details.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.inner.name()
+    }
+
+    /// Sends a stop request to the corresponding [`Actor`].
+    ///
+    /// See [`Address::stop`] for details.
+    pub async fn stop(&mut self) {
+        self.inner.stop().await;
+    }
+
+    /// Creates a future that waits for actor to be fully stopped.
+    ///
+    /// See [`Address::wait_for_stop`] for details.
+    pub async fn wait_for_stop(&self) -> StopReason {
+        self.inner.wait_for_stop().await
+    }
+}
@@ -1,38 +1,225 @@
 //! [`Registry`] provides a way to get addresses of singleton-like addresses
 //! by automatically managing their lifetime under the hood.
 //!
+//! Registries are independent of each other: a service spawned by one registry
+//! is not visible to the others. For convenience, there is a process-wide registry
+//! available via [`Registry::global`], and each [`ActorSystem`](crate::system::ActorSystem)
+//! has its own one.
+//!
 //! This module is awailable only when `messages` is build with one of the supported
 //! runtime features enabled, as it needs to spawn actors.
 
 use std::{
     any::{Any, TypeId},
     collections::HashMap,
+    convert::Infallible,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, PoisonError, Weak,
+    },
+    time::{Duration, Instant},
 };
 
-use futures::lock::Mutex;
+use async_trait::async_trait;
+use futures::{lock::Mutex, Stream};
 use once_cell::sync::Lazy;
 
-use crate::prelude::{Actor, Address, RuntimeActorExt};
+use crate::{
+    actor::ActorId,
+    errors::RegistryError,
+    prelude::{Actor, Address, Context, WeakAddress},
+    system::Tracker,
+};
 
+#[allow(clippy::non_std_lazy_statics)] // `once_cell` is used to support older compilers.
 static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
 
 /// Extension of an [`Actor`] that can be managed by [`Registry`].
+///
+/// Services are created via [`Default`] trait. If creation of a service requires
+/// some asynchronous work or may fail, [`AsyncService`] should be implemented instead.
 pub trait Service: Actor + Default {
     /// Service name.
     ///
-    /// **Note:** All the service names must be unique. Having two services
-    /// with the same name may result in [`Registry::service`] function panic.
+    /// Services are identified by their type, so the name is only used for diagnostics
+    /// and doesn't have to be unique.
     const NAME: &'static str;
+
+    /// Defines what happens if the service is requested after it was stopped.
+    ///
+    /// By default, service is restarted right away.
+    const RESTART: RestartPolicy = RestartPolicy::Always;
+}
+
+/// Extension of an [`Actor`] that can be managed by [`Registry`] and requires
+/// asynchronous and/or fallible initialization (e.g. it has to read the configuration
+/// or to open a database connection).
+///
+/// Every [`Service`] is an `AsyncService` that can't fail to be created.
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// #[derive(Default)]
+/// struct Config;
+///
+/// impl Actor for Config {}
+///
+/// #[async_trait]
+/// impl Handler<()> for Config {
+///     type Result = String;
+///
+///     async fn handle(&mut self, _: (), context: &Context<Self>) -> String {
+///         "postgres://localhost".into()
+///     }
+/// }
+///
+/// impl Service for Config {
+///     const NAME: &'static str = "Config";
+/// }
+///
+/// struct Database {
+///     url: String,
+/// }
+///
+/// impl Actor for Database {}
+///
+/// #[async_trait]
+/// impl AsyncService for Database {
+///     const NAME: &'static str = "Database";
+///     type Error = SendError;
+///
+///     async fn create(registry: &Registry) -> Result<Self, SendError> {
+///         // Services can depend on other services.
+///         let mut config: Address<Config> = registry.service().await;
+///         let url = config.send(()).await?;
+///         Ok(Self { url })
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///    let registry = Registry::new();
+///    let database: Address<Database> = registry.try_service().await.unwrap();
+/// }
+/// ```
+#[async_trait]
+pub trait AsyncService: Actor {
+    /// Service name.
+    ///
+    /// Services are identified by their type, so the name is only used for diagnostics
+    /// and doesn't have to be unique.
+    const NAME: &'static str;
+
+    /// Defines what happens if the service is requested after it was stopped.
+    ///
+    /// By default, service is restarted right away.
+    const RESTART: RestartPolicy = RestartPolicy::Always;
+
+    /// Error that can occur during the service creation.
+    ///
+    /// Error is cloned, so that every request that was waiting for the failed creation
+    /// attempt receives it.
+    type Error: Clone + Send + 'static;
+
+    /// Creates the service.
+    ///
+    /// `registry` is the registry the service is requested from, so it can be used to
+    /// get other services. Note that circular dependencies between services result in
+    /// a deadlock.
+    async fn create(registry: &Registry) -> Result<Self, Self::Error>;
+}
+
+#[async_trait]
+impl<S: Service> AsyncService for S {
+    const NAME: &'static str = <S as Service>::NAME;
+    const RESTART: RestartPolicy = <S as Service>::RESTART;
+
+    type Error = Infallible;
+
+    async fn create(_registry: &Registry) -> Result<Self, Infallible> {
+        Ok(S::default())
+    }
+}
+
+/// Defines what [`Registry`] does if a service is requested after it was stopped
+/// (e.g. it panicked or was stopped via [`Address::stop`]).
+///
+/// Services are restarted lazily: a new instance is created once the service is requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Service is restarted right away.
+    Always,
+    /// Service is not restarted, and requests for it result in [`RegistryError::ServiceStopped`].
+    ///
+    /// Service can still be started again once it's removed via [`Registry::unregister`].
+    Never,
+    /// Service is restarted after a delay. The first restart is delayed by `initial`,
+    /// and each next one is delayed twice as long, but no longer than `max`.
+    ///
+    /// If the stopped instance of the service was started at least `max` ago, it's
+    /// considered to be running successfully, and the delay starts from `initial` again.
+    ///
+    /// Registry is not blocked during the delay, and concurrent requests for the service
+    /// wait for the same restart.
+    Backoff {
+        /// Delay before the first restart.
+        initial: Duration,
+        /// Maximum delay before the restart.
+        max: Duration,
+    },
+}
+
+/// Event emitted by the [`Registry`] once a service is spawned.
+///
+/// See [`Registry::events`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceEvent {
+    /// Service was started for the first time.
+    Started {
+        /// Name of the service.
+        name: &'static str,
+        /// Identifier of the service actor.
+        id: ActorId,
+    },
+    /// Service was restarted after being stopped.
+    Restarted {
+        /// Name of the service.
+        name: &'static str,
+        /// Identifier of the new service actor.
+        id: ActorId,
+        /// Amount of the service restarts, including this one.
+        restarts: usize,
+    },
 }
 
 /// `Registry` is an manager object providing access to the addresses
-/// of [`Actor`]s that implement [`Service`] trait.
+/// of [`Actor`]s that implement [`Service`] or [`AsyncService`] trait.
 ///
 /// `Registry` maintains a list of spawned services and when an address
 /// of a service is requested, it checks whether the corresponding actor is
 /// already running. If so, address of this actor is returned. Otherwise,
 /// actor is spawned first.
 ///
+/// Services are created lazily, once the address of a service is requested for the first
+/// time. If a service is requested concurrently while it's being created, all the requests
+/// wait for the single instance to be created.
+///
+/// If a service was stopped, it's restarted according to its [`RestartPolicy`].
+///
+/// ## Named actors
+///
+/// Besides the services, registry can store addresses of arbitrary actors under a string key
+/// via [`Registry::register_named`], which is useful if there are many actors of the same type
+/// (e.g. one per tenant or per connection). Unlike services, named actors are not kept
+/// alive by the registry, and they are removed from it once stopped.
+///
+/// Registry keeps the services alive: they are stopped once the registry is dropped
+/// (unless there are other addresses of them).
+///
 /// ## Stopping and resuming
 ///
 /// Services managed by the `Registry` may still be stopped via the [`Address::stop`] method.
@@ -57,21 +244,66 @@ pub trait Service: Actor + Default {
 ///
 /// #[tokio::main]
 /// async fn main() {
-///    let mut addr: Address<Ping> = Registry::service().await;
+///    let registry = Registry::new();
+///    let mut addr: Address<Ping> = registry.service().await;
+///
+///    // Process-wide registry is available as well.
+///    let mut global_addr: Address<Ping> = Registry::global().service().await;
+///    assert_ne!(addr.id(), global_addr.id());
 ///    # addr.stop().await;
 ///    # addr.wait_for_stop().await;
+///    # global_addr.stop().await;
+///    # global_addr.wait_for_stop().await;
 /// }
 /// ```
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct Registry {
-    services: Mutex<HashMap<&'static str, Box<dyn Any + Send>>>,
+    services: Mutex<HashMap<TypeId, Arc<ServiceSlot>>>,
+    named: Arc<NamedActors>,
+    /// Senders of the streams returned by `Registry::events`.
+    subscribers: std::sync::Mutex<Vec<EventSender>>,
+    /// Tracker of the `ActorSystem` that owns the registry, if any.
+    tracker: Option<Tracker>,
+}
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Registry may be locked at the moment, in that case services are not shown.
+        let services = self
+            .services
+            .try_lock()
+            .map(|services| services.values().map(|slot| slot.name).collect::<Vec<_>>());
+        f.debug_struct("Registry")
+            .field("services", &services)
+            .field(
+                "named",
+                &self.named.lock().values().map(HashMap::len).sum::<usize>(),
+            )
+            .finish_non_exhaustive()
+    }
 }
 
 impl Registry {
-    fn new() -> Self {
+    /// Creates a new empty `Registry`.
+    #[must_use]
+    pub fn new() -> Self {
         Self::default()
     }
 
+    /// Creates a registry that reports the spawned services to the `ActorSystem`.
+    pub(crate) fn with_tracker(tracker: Tracker) -> Self {
+        Self {
+            tracker: Some(tracker),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the process-wide registry.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &REGISTRY
+    }
+
     /// Returns an address of an actor that implements [`Service`] trait.
     ///
     /// This function checks whether the corresponding actor is
@@ -80,37 +312,414 @@ impl Registry {
     ///
     /// ## Panics
     ///
-    /// This method panics if two services having the same name will be attempted
-    /// to be instantiated. All the names of services are expected to be unique.
-    pub async fn service<S: Service + Sized + 'static>() -> Address<S> {
-        let mut lock = REGISTRY.services.lock().await;
-
-        // Check whether address is already in registry.
-        if let Some(maybe_addr) = lock.get(S::NAME) {
-            // Check whether we can downcast the stored address to a desired type.
-            if let Some(addr) = maybe_addr.downcast_ref::<Address<S>>() {
-                // Check whether actor is running. It for some reason is was stopped,
-                // we will have to re-launch it again.
+    /// This method panics if the registry is corrupted or if the service was stopped
+    /// and can't be restarted, see [`Registry::try_service`]
+    /// for the fallible version.
+    pub async fn service<S: Service + Sized + 'static>(&self) -> Address<S> {
+        match self.try_service::<S>().await {
+            Ok(addr) => addr,
+            Err(err) => panic!("Unable to get the service: {}", err),
+        }
+    }
+
+    /// Returns an address of an actor that implements [`AsyncService`] trait
+    /// (which includes all the [`Service`]s).
+    ///
+    /// This function checks whether the corresponding actor is already running.
+    /// If so, address of this actor is returned. Otherwise, actor is created via
+    /// [`AsyncService::create`] and spawned first.
+    ///
+    /// If the service creation fails, the next request will attempt to create it again.
+    /// Requests that were waiting for the failed attempt receive the same error.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`RegistryError::CreationFailed`] if the service creation failed,
+    /// [`RegistryError::ServiceStopped`] if the service was stopped and its
+    /// [`RestartPolicy`] doesn't allow to restart it, or [`RegistryError::TypeMismatch`]
+    /// if the address stored in the registry can't be converted to the address
+    /// of the requested service.
+    pub async fn try_service<S: AsyncService>(
+        &self,
+    ) -> Result<Address<S>, RegistryError<S::Error>> {
+        let mut slot = self.slot::<S>().await;
+        let mut failures = slot.failures.load(Ordering::Acquire);
+        // Lock is held during the service creation, so concurrent requests wait for it.
+        let mut state = slot.state.lock().await;
+
+        let restart = loop {
+            // Check whether address is already in registry.
+            let restart = if let Some(stored) = state.address.as_ref() {
+                let addr = downcast_ref::<S, _>(&**stored)?;
                 if addr.connected() {
-                    return addr.clone();
+                    return Ok(addr.clone());
                 }
+                // Actor was stopped, so it has to be restarted.
+                true
             } else {
-                // Two or more services have a not unique name.
-                panic!(
-                    "Two or more services have a not unique name. \
-                    Name is {}, attempt to retrieve the type {:?}, but stored type is {:?}",
-                    S::NAME,
-                    TypeId::of::<Address<S>>(),
-                    (&*maybe_addr).type_id()
-                );
+                false
+            };
+
+            // Creation attempt failed while this request was waiting for it,
+            // so its error is shared instead of making another attempt.
+            if slot.failures.load(Ordering::Acquire) != failures {
+                if let Some(err) = state.error.as_ref().and_then(|err| err.downcast_ref()) {
+                    return Err(RegistryError::CreationFailed(S::Error::clone(err)));
+                }
             }
-        }
+
+            if restart {
+                match S::RESTART {
+                    RestartPolicy::Always => {}
+                    RestartPolicy::Never => {
+                        return Err(RegistryError::ServiceStopped { name: S::NAME })
+                    }
+                    RestartPolicy::Backoff { initial, max } => {
+                        let now = Instant::now();
+                        let deadline = if let Some(deadline) = state.restart_at {
+                            // Restart was already scheduled by a concurrent request.
+                            deadline
+                        } else {
+                            // Delay is reset if the stopped instance was running for long enough.
+                            // Stop may not be noticed by the watcher yet, then it happened just now.
+                            let stopped = state.stopped_at.unwrap_or(now);
+                            let started = state.started_at.unwrap_or(stopped);
+                            if stopped.duration_since(started) >= max {
+                                state.recent_restarts = 0;
+                            }
+                            let deadline = now
+                                + crate::supervisor::backoff(initial, max, state.recent_restarts);
+                            state.restart_at = Some(deadline);
+                            deadline
+                        };
+
+                        if now < deadline {
+                            // Lock is released during the delay, so the service can be accessed
+                            // (e.g. unregistered) in the meantime. Once the delay is over,
+                            // the state is checked again.
+                            drop(state);
+                            crate::runtime::sleep(deadline - now).await;
+                            slot = self.slot::<S>().await;
+                            failures = slot.failures.load(Ordering::Acquire);
+                            state = slot.state.lock().await;
+                            continue;
+                        }
+                    }
+                }
+            }
+
+            break restart;
+        };
 
         // Address is either not in the registry or has been stopped.
         // We now have to spawn and store it in the registry.
-        let addr = S::default().spawn();
-        lock.insert(S::NAME, Box::new(addr.clone()));
+        let service = match S::create(self).await {
+            Ok(service) => service,
+            Err(err) => {
+                state.error = Some(Box::new(err.clone()));
+                slot.failures.fetch_add(1, Ordering::Release);
+                return Err(RegistryError::CreationFailed(err));
+            }
+        };
+        state.error = None;
+        let addr = Context::new().spawn(service);
+        self.store(&slot, &mut state, &addr);
+
+        let event = if restart {
+            state.restarts += 1;
+            state.recent_restarts += 1;
+            ServiceEvent::Restarted {
+                name: S::NAME,
+                id: addr.id(),
+                restarts: state.restarts,
+            }
+        } else {
+            ServiceEvent::Started {
+                name: S::NAME,
+                id: addr.id(),
+            }
+        };
+        self.emit(event);
+
+        Ok(addr)
+    }
+
+    /// Returns the amount of times the service was restarted by the registry.
+    pub async fn restarts<S: AsyncService>(&self) -> usize {
+        self.slot::<S>().await.state.lock().await.restarts
+    }
+
+    /// Returns a stream of [`ServiceEvent`]s, i.e. starts and restarts of the services.
+    ///
+    /// Only the events that happen after this method is called are emitted.
+    /// Stream is unbounded, so it should be polled in order not to accumulate events.
+    pub fn events(&self) -> impl Stream<Item = ServiceEvent> + Send + Unpin + 'static {
+        let (sender, receiver) = async_channel::unbounded();
+        self.lock_subscribers().push(sender);
+        receiver
+    }
+
+    fn emit(&self, event: ServiceEvent) {
+        // Dropped streams are removed.
+        self.lock_subscribers()
+            .retain(|subscriber| subscriber.try_send(event).is_ok());
+    }
+
+    fn lock_subscribers(&self) -> std::sync::MutexGuard<'_, Vec<EventSender>> {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Stores an address of an already running service in the registry, so it will
+    /// be returned by [`Registry::service`] instead of spawning a new actor.
+    ///
+    /// Returns the previously stored address of the service, if any.
+    pub async fn register<S: AsyncService>(&self, address: Address<S>) -> Option<Address<S>> {
+        let slot = self.slot::<S>().await;
+        let mut state = slot.state.lock().await;
+        let previous = self.store(&slot, &mut state, &address);
+        previous.and_then(|previous| previous.downcast().ok().map(|addr| *addr))
+    }
+
+    /// Removes the service from the registry and returns its address, if it was stored.
+    ///
+    /// Note that the service is stopped once all of its addresses are dropped, so
+    /// if the returned address is not used, the service will be stopped.
+    pub async fn unregister<S: AsyncService>(&self) -> Option<Address<S>> {
+        let slot = self.services.lock().await.remove(&TypeId::of::<S>())?;
+        let previous = slot.state.lock().await.address.take();
+        previous.and_then(|previous| previous.downcast().ok().map(|addr| *addr))
+    }
+
+    /// Stores an address of an actor under the provided key.
+    ///
+    /// Keys are scoped by the actor type, so actors of different types can use the same key.
+    /// Registry does not keep the actor alive, and the entry is removed once the actor is stopped.
+    ///
+    /// Returns the address of the actor previously stored under the same key, if it's still running.
+    ///
+    /// ## Examples
+    ///
+    /// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+    ///
+    /// ```rust
+    /// # use messages::prelude::*;
+    /// struct Tenant;
+    ///
+    /// impl Actor for Tenant {}
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let registry = Registry::new();
+    ///    let mut first = Tenant.spawn();
+    ///    registry.register_named("first", &first);
+    ///    registry.register_named("second", &Tenant.spawn());
+    ///
+    ///    assert_eq!(registry.lookup::<Tenant>("first"), Some(first.clone()));
+    ///    // Registry doesn't keep actors alive, so the second tenant is already stopped.
+    ///    assert!(registry.lookup::<Tenant>("second").is_none());
+    ///    assert_eq!(registry.named_actors::<Tenant>().len(), 1);
+    ///
+    ///    first.stop().await;
+    ///    first.wait_for_stop().await;
+    ///    assert!(registry.lookup::<Tenant>("first").is_none());
+    /// }
+    /// ```
+    pub fn register_named<A: Actor>(
+        &self,
+        key: impl Into<String>,
+        address: &Address<A>,
+    ) -> Option<Address<A>> {
+        let key = key.into();
+        let weak = address.downgrade();
+        let previous = self
+            .named
+            .lock()
+            .entry(TypeId::of::<A>())
+            .or_default()
+            .insert(key.clone(), Box::new(weak.clone()));
+        let previous = previous.and_then(|previous| downcast_weak::<A>(&*previous).cloned());
+
+        // Remove the entry once the actor is stopped. Task doesn't keep the registry alive.
+        // If the actor was already stored under this key, its watcher is still running.
+        if previous.as_ref().map(WeakAddress::id) != Some(weak.id()) {
+            let named = Arc::downgrade(&self.named);
+            crate::runtime::spawn(async move {
+                weak.wait_for_stop().await;
+                NamedActors::remove_stopped(&named, &key, &weak);
+            });
+        }
+
+        previous.and_then(|previous| previous.upgrade())
+    }
+
+    /// Returns the address of an actor stored under the provided key via
+    /// [`Registry::register_named`], if the actor is still running.
+    #[must_use]
+    pub fn lookup<A: Actor>(&self, key: &str) -> Option<Address<A>> {
+        let named = self.named.lock();
+        let stored = named.get(&TypeId::of::<A>())?.get(key)?;
+        downcast_weak::<A>(&**stored)?.upgrade()
+    }
+
+    /// Removes an actor stored under the provided key from the registry,
+    /// and returns its address if the actor is still running.
+    pub fn unregister_named<A: Actor>(&self, key: &str) -> Option<Address<A>> {
+        let mut named = self.named.lock();
+        let stored = named.get_mut(&TypeId::of::<A>())?.remove(key)?;
+        downcast_weak::<A>(&*stored)?.upgrade()
+    }
+
+    /// Returns all the running actors of the provided type stored via
+    /// [`Registry::register_named`], sorted by their keys.
+    #[must_use]
+    pub fn named_actors<A: Actor>(&self) -> Vec<(String, Address<A>)> {
+        let named = self.named.lock();
+        let mut actors: Vec<_> = named
+            .get(&TypeId::of::<A>())
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, stored)| {
+                let address = downcast_weak::<A>(&**stored)?.upgrade()?;
+                Some((key.clone(), address))
+            })
+            .collect();
+        actors.sort_by(|(a, _), (b, _)| a.cmp(b));
+        actors
+    }
+
+    /// Removes all the services and named actors from the registry.
+    ///
+    /// Services that don't have other addresses are stopped.
+    pub async fn clear(&self) {
+        self.services.lock().await.clear();
+        self.named.lock().clear();
+    }
+
+    /// Stores the address of a running service in its slot, and returns the previously
+    /// stored address.
+    fn store<S: AsyncService>(
+        &self,
+        slot: &Arc<ServiceSlot>,
+        state: &mut ServiceState,
+        addr: &Address<S>,
+    ) -> Option<Box<dyn Any + Send>> {
+        if let Some(tracker) = &self.tracker {
+            tracker.track(addr);
+        }
+        state.started_at = Some(Instant::now());
+        state.stopped_at = None;
+        state.restart_at = None;
+
+        // Remember when the service is stopped, so the restart delay can be calculated.
+        // Task doesn't keep the slot alive.
+        let slot = Arc::downgrade(slot);
+        let weak = addr.downgrade();
+        crate::runtime::spawn(async move {
+            weak.wait_for_stop().await;
+            let stopped_at = Instant::now();
+            if let Some(slot) = slot.upgrade() {
+                let mut state = slot.state.lock().await;
+                let current = state
+                    .address
+                    .as_ref()
+                    .and_then(|stored| stored.downcast_ref::<Address<S>>());
+                // Service could be replaced in the meantime.
+                if matches!(current, Some(current) if current.id() == weak.id()) {
+                    state.stopped_at = Some(stopped_at);
+                }
+            }
+        });
 
-        addr
+        state.address.replace(Box::new(addr.clone()))
     }
+
+    /// Returns the slot of the service, creating it if needed.
+    async fn slot<S: AsyncService>(&self) -> Arc<ServiceSlot> {
+        let mut services = self.services.lock().await;
+        services
+            .entry(TypeId::of::<S>())
+            .or_insert_with(|| {
+                Arc::new(ServiceSlot {
+                    name: S::NAME,
+                    failures: AtomicUsize::new(0),
+                    state: Mutex::default(),
+                })
+            })
+            .clone()
+    }
+}
+
+/// Place for the address of a service in the [`Registry`].
+///
+/// Each service has its own lock, so services can be created independently
+/// (e.g. one service can request another one during its creation).
+struct ServiceSlot {
+    /// Name of the service, used for diagnostics.
+    name: &'static str,
+    /// Amount of failed creation attempts, which can be checked without the lock.
+    failures: AtomicUsize,
+    state: Mutex<ServiceState>,
+}
+
+#[derive(Default)]
+struct ServiceState {
+    address: Option<Box<dyn Any + Send>>,
+    /// Amount of times the service was restarted.
+    restarts: usize,
+    /// Amount of restarts since the service was last running for long enough,
+    /// used to calculate the [`RestartPolicy::Backoff`] delay.
+    recent_restarts: usize,
+    /// Time when the current instance of the service was started.
+    started_at: Option<Instant>,
+    /// Time when the current instance of the service was stopped, if it was noticed.
+    stopped_at: Option<Instant>,
+    /// Time when the service is going to be restarted, if the restart is delayed.
+    restart_at: Option<Instant>,
+    /// Error of the last creation attempt, if it failed.
+    error: Option<Box<dyn Any + Send>>,
+}
+
+/// Weak addresses of the named actors, grouped by the actor type.
+#[derive(Default)]
+struct NamedActors(std::sync::Mutex<NamedActorsMap>);
+
+type NamedActorsMap = HashMap<TypeId, HashMap<String, Box<dyn Any + Send>>>;
+
+type EventSender = async_channel::Sender<ServiceEvent>;
+
+impl NamedActors {
+    fn lock(&self) -> std::sync::MutexGuard<'_, NamedActorsMap> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Removes the entry of the stopped actor, unless the key was reused by another actor.
+    fn remove_stopped<A: Actor>(this: &Weak<Self>, key: &str, stopped: &WeakAddress<A>) {
+        // Registry may be already dropped.
+        if let Some(this) = this.upgrade() {
+            let mut named = this.lock();
+            if let Some(actors) = named.get_mut(&TypeId::of::<A>()) {
+                let stored = actors
+                    .get(key)
+                    .and_then(|stored| downcast_weak::<A>(&**stored));
+                if matches!(stored, Some(stored) if stored.id() == stopped.id()) {
+                    actors.remove(key);
+                }
+            }
+        }
+    }
+}
+
+fn downcast_weak<A: Actor>(stored: &(dyn Any + Send)) -> Option<&WeakAddress<A>> {
+    stored.downcast_ref()
+}
+
+/// Converts the stored address to the address of the requested service.
+fn downcast_ref<S: AsyncService, E>(
+    stored: &(dyn Any + Send),
+) -> Result<&Address<S>, RegistryError<E>> {
+    stored
+        .downcast_ref()
+        .ok_or(RegistryError::TypeMismatch { name: S::NAME })
 }
@@ -1,4 +1,4 @@
-use std::future::Future;
+use std::{future::Future, time::Duration};
 
 pub use async_std::task::JoinHandle;
 
@@ -9,3 +9,12 @@ where
 {
     async_std::task::spawn(task)
 }
+
+pub(crate) async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await;
+}
+
+/// Waits for the task to complete.
+pub(crate) async fn join<T>(handle: JoinHandle<T>) -> T {
+    handle.await
+}
@@ -28,3 +28,22 @@ cfg_runtime! {
     pub use runtime_impl::JoinHandle;
     pub(crate) use runtime_impl::*;
 }
+
+cfg_runtime! {
+    use std::{future::Future, time::Instant};
+
+    /// Runs the future until the provided deadline.
+    ///
+    /// Returns `None` if the deadline was reached before the future completed.
+    pub(crate) async fn timeout_at<F: Future>(deadline: Instant, future: F) -> Option<F::Output> {
+        use futures::future::{select, Either};
+
+        let timer = sleep(deadline.saturating_duration_since(Instant::now()));
+        futures::pin_mut!(future, timer);
+
+        match select(future, timer).await {
+            Either::Left((output, _)) => Some(output),
+            Either::Right(_) => None,
+        }
+    }
+}
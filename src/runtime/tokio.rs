@@ -1,4 +1,4 @@
-use std::future::Future;
+use std::{future::Future, time::Duration};
 
 pub use tokio::task::JoinHandle;
 
@@ -9,3 +9,16 @@ where
 {
     tokio::spawn(task)
 }
+
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Waits for the task to complete, propagating the panic of the task (if any).
+pub(crate) async fn join<T>(handle: JoinHandle<T>) -> T {
+    match handle.await {
+        Ok(output) => output,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(err) => panic!("Task was cancelled: {}", err),
+    }
+}
@@ -0,0 +1,344 @@
+//! Supervisors are responsible for restarting failed actors.
+//!
+//! [`Supervisor`] owns factories of its child actors and runs them. Once a child
+//! fails (i.e. it was stopped with [`StopReason::Panicked`]), supervisor creates a new
+//! instance of the actor using the factory, and applies the configured [`RestartStrategy`]
+//! to the rest of the children.
+//!
+//! Mailbox of a supervised actor survives the actor instance, so the addresses
+//! returned by [`Supervisor::add_child`] stay valid across restarts: messages that
+//! were sent while the actor was being restarted will be processed by the new instance.
+//!
+//! Children that stopped for any other reason (e.g. via [`Address::stop`] or because
+//! all of their addresses were dropped) are not restarted.
+//!
+//! In order to prevent infinite restart loops, supervisor has a restart intensity:
+//! if there were more than `max_restarts` restarts during the `window`, supervisor stops
+//! all of its children (which observe [`StopReason::SupervisorShutdown`]) and exits with
+//! [`SupervisorError::RestartIntensityExceeded`].
+//! Additionally, each consecutive restart within the window is delayed exponentially.
+
+use std::{
+    collections::VecDeque,
+    convert::TryFrom,
+    time::{Duration, Instant},
+};
+
+use futures::{future::BoxFuture, stream::FuturesUnordered, FutureExt, StreamExt};
+
+use crate::{
+    actor::{Actor, StopReason},
+    address::Address,
+    context::Context,
+    errors::SupervisorError,
+    runtime::JoinHandle,
+};
+
+/// Defines which children are restarted once one of the children fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartStrategy {
+    /// Only the failed child is restarted.
+    OneForOne,
+    /// All the children are restarted.
+    OneForAll,
+    /// The failed child and all the children added after it are restarted.
+    RestForOne,
+}
+
+type Factory<A> = Box<dyn FnMut(&Context<A>) -> A + Send>;
+
+type ChildRun = BoxFuture<'static, (Box<dyn Child>, StopReason)>;
+
+/// Object-safe interface of a supervised actor.
+trait Child: Send {
+    /// Runs a new actor instance after the provided delay.
+    fn start(self: Box<Self>, delay: Duration) -> ChildRun;
+
+    /// Finishes the supervision, so the addresses are notified about the actor stop.
+    fn finish(self: Box<Self>, reason: StopReason);
+}
+
+struct SupervisedActor<A> {
+    context: Context<A>,
+    factory: Factory<A>,
+}
+
+impl<A: Actor> Child for SupervisedActor<A> {
+    fn start(mut self: Box<Self>, delay: Duration) -> ChildRun {
+        // Stop requests issued before this point were meant for the previous instance.
+        self.context.clear_stop_requests();
+
+        async move {
+            crate::runtime::sleep(delay).await;
+            let actor = (self.factory)(&self.context);
+            let outcome = self.context.run_instance(actor).await;
+            (self as Box<dyn Child>, outcome.reason)
+        }
+        .boxed()
+    }
+
+    fn finish(self: Box<Self>, reason: StopReason) {
+        self.context.finish(reason);
+    }
+}
+
+struct ChildState {
+    /// Stops the current instance of the child without closing its mailbox.
+    interrupt: Box<dyn Fn(StopReason) + Send + Sync>,
+    /// Delay before the restart, if the child was stopped as a part of a group restart.
+    pending_restart: Option<Duration>,
+    /// Whether an instance of the child is running (or is about to be started).
+    running: bool,
+    /// Time when the current instance of the child is started, once the restart delay is over.
+    starts_at: Instant,
+}
+
+impl ChildState {
+    /// Marks the child as running, with the new instance started after the provided delay.
+    fn start(&mut self, delay: Duration) {
+        self.running = true;
+        self.starts_at = Instant::now() + delay;
+    }
+}
+
+/// `Supervisor` runs a group of actors and restarts them once they fail.
+///
+/// See the [module documentation](crate::supervisor) for details.
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// # use std::time::Duration;
+/// struct Divider;
+///
+/// #[async_trait]
+/// impl Actor for Divider {}
+///
+/// #[async_trait]
+/// impl Handler<(u32, u32)> for Divider {
+///     type Result = u32;
+///
+///     async fn handle(&mut self, (a, b): (u32, u32), context: &Context<Self>) -> u32 {
+///         a / b
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut supervisor = Supervisor::new(RestartStrategy::OneForOne)
+///         .with_restart_intensity(3, Duration::from_secs(5))
+///         .with_backoff(Duration::from_millis(10), Duration::from_secs(1));
+///     let mut address = supervisor.add_child(|_context| Divider);
+///     let supervisor = supervisor.spawn();
+///
+///     // Division by zero causes a panic, so the actor will be restarted.
+///     assert!(address.send((1, 0)).await.is_err());
+///     // Address is still valid.
+///     assert_eq!(address.send((4, 2)).await.unwrap(), 2);
+///
+///     address.stop().await;
+///     address.wait_for_stop().await;
+///     # supervisor.await.unwrap().unwrap();
+/// }
+/// ```
+pub struct Supervisor {
+    strategy: RestartStrategy,
+    max_restarts: usize,
+    window: Duration,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    children: Vec<(ChildState, Box<dyn Child>)>,
+}
+
+impl std::fmt::Debug for Supervisor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Supervisor")
+            .field("strategy", &self.strategy)
+            .field("max_restarts", &self.max_restarts)
+            .field("window", &self.window)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("children", &self.children.len())
+            .finish()
+    }
+}
+
+impl Supervisor {
+    /// Creates a new `Supervisor` with the provided restart strategy.
+    ///
+    /// By default, supervisor allows 3 restarts in 5 seconds, and the restart delay
+    /// starts with 10 milliseconds and is limited by 1 second.
+    #[must_use]
+    pub fn new(strategy: RestartStrategy) -> Self {
+        Self {
+            strategy,
+            max_restarts: 3,
+            window: Duration::from_secs(5),
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the maximum amount of restarts allowed within the provided time window.
+    #[must_use]
+    pub fn with_restart_intensity(mut self, max_restarts: usize, window: Duration) -> Self {
+        self.max_restarts = max_restarts;
+        self.window = window;
+        self
+    }
+
+    /// Sets the restart delay.
+    ///
+    /// The first restart within the restart intensity window is delayed by `initial`,
+    /// and each next one is delayed twice as long, but no longer than `max`.
+    #[must_use]
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Adds a child actor created by the provided factory.
+    ///
+    /// Factory is invoked every time the actor is (re)started.
+    /// Returned address stays valid across restarts.
+    pub fn add_child<A, F>(&mut self, factory: F) -> Address<A>
+    where
+        A: Actor,
+        F: FnMut(&Context<A>) -> A + Send + 'static,
+    {
+        self.add_child_with_context(Context::new(), factory)
+    }
+
+    /// Same as [`Supervisor::add_child`], but allows to provide a custom [`Context`]
+    /// (e.g. with a custom mailbox configuration).
+    pub fn add_child_with_context<A, F>(&mut self, context: Context<A>, factory: F) -> Address<A>
+    where
+        A: Actor,
+        F: FnMut(&Context<A>) -> A + Send + 'static,
+    {
+        let address = context.address();
+        let state = ChildState {
+            interrupt: Box::new(context.interrupter()),
+            pending_restart: None,
+            running: false,
+            starts_at: Instant::now(),
+        };
+        let child = SupervisedActor {
+            context,
+            factory: Box::new(factory),
+        };
+        self.children.push((state, Box::new(child)));
+        address
+    }
+
+    /// Spawns the supervisor.
+    ///
+    /// Returned join handle resolves once all the children are stopped.
+    #[allow(clippy::must_use_candidate)] // Supervisor can be left running in the background.
+    pub fn spawn(self) -> JoinHandle<Result<(), SupervisorError>> {
+        crate::runtime::spawn(self.run())
+    }
+
+    /// Runs the children and supervises them until all of them are stopped.
+    ///
+    /// Future returned by this method should not normally be directly `await`ed,
+    /// but rather is expected to be used in some kind of `spawn` function of
+    /// the used runtime (e.g. `tokio::spawn` or `async_std::task::spawn`).
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`SupervisorError::RestartIntensityExceeded`] if children were stopped
+    /// because they failed too often.
+    pub async fn run(self) -> Result<(), SupervisorError> {
+        let mut states = Vec::with_capacity(self.children.len());
+        let mut running = FuturesUnordered::new();
+        for (index, (mut state, child)) in self.children.into_iter().enumerate() {
+            state.start(Duration::from_secs(0));
+            states.push(state);
+            running.push(Self::start(index, child, Duration::from_secs(0)));
+        }
+
+        let mut restarts = VecDeque::new();
+        let mut result = Ok(());
+        while let Some((index, child, reason)) = running.next().await {
+            states[index].running = false;
+
+            // Child was stopped as a part of a group restart.
+            if let Some(delay) = states[index].pending_restart.take() {
+                states[index].start(delay);
+                running.push(Self::start(index, child, delay));
+                continue;
+            }
+
+            // Child stopped on its own or supervisor is shutting down.
+            if reason != StopReason::Panicked || result.is_err() {
+                child.finish(reason);
+                continue;
+            }
+
+            let now = Instant::now();
+            while matches!(restarts.front(), Some(&time) if now.duration_since(time) > self.window)
+            {
+                restarts.pop_front();
+            }
+
+            if restarts.len() >= self.max_restarts {
+                // Children fail too often, there is no point in restarting them.
+                result = Err(SupervisorError::RestartIntensityExceeded);
+                child.finish(reason);
+                for state in states.iter_mut().filter(|state| state.running) {
+                    state.pending_restart = None;
+                    (state.interrupt)(StopReason::SupervisorShutdown);
+                }
+                continue;
+            }
+
+            let delay = backoff(self.initial_backoff, self.max_backoff, restarts.len());
+            restarts.push_back(now);
+
+            let affected = match self.strategy {
+                RestartStrategy::OneForOne => index..=index,
+                RestartStrategy::OneForAll => 0..=states.len() - 1,
+                RestartStrategy::RestForOne => index..=states.len() - 1,
+            };
+            for sibling in affected.filter(|&sibling| sibling != index) {
+                let state = &mut states[sibling];
+                // Sibling that is still waiting for its restart delay is not interrupted:
+                // its new instance will be started after this failure anyway.
+                if state.running && state.pending_restart.is_none() && state.starts_at <= now {
+                    state.pending_restart = Some(delay);
+                    (state.interrupt)(StopReason::Restarting);
+                }
+            }
+
+            states[index].start(delay);
+            running.push(Self::start(index, child, delay));
+        }
+
+        result
+    }
+
+    fn start(
+        index: usize,
+        child: Box<dyn Child>,
+        delay: Duration,
+    ) -> BoxFuture<'static, (usize, Box<dyn Child>, StopReason)> {
+        child
+            .start(delay)
+            .map(move |(child, reason)| (index, child, reason))
+            .boxed()
+    }
+}
+
+/// Calculates the restart delay given the amount of recent restarts.
+pub(crate) fn backoff(initial: Duration, max: Duration, restarts: usize) -> Duration {
+    let factor = 2_u32.saturating_pow(u32::try_from(restarts).unwrap_or(u32::MAX));
+    initial
+        .checked_mul(factor)
+        .map_or(max, |delay| delay.min(max))
+}
@@ -0,0 +1,302 @@
+//! [`ActorSystem`] keeps track of the spawned actors, so that all of them can
+//! be stopped together (e.g. once the application is shutting down).
+//!
+//! This module is available only when `messages` is built with one of the supported
+//! runtime features enabled, as it needs to spawn actors.
+
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use futures::{future::BoxFuture, FutureExt};
+
+use crate::{
+    actor::{Actor, ActorId, StopReason},
+    address::{Address, WeakAddress},
+    context::Context,
+    registry::Registry,
+};
+
+/// Object-safe interface of an actor tracked by the [`ActorSystem`].
+trait Tracked: Send + Sync {
+    fn id(&self) -> ActorId;
+
+    fn info(&self) -> ActorInfo;
+
+    fn is_stopped(&self) -> bool;
+
+    fn stop(&self);
+
+    fn wait_for_stop(&self) -> BoxFuture<'_, StopReason>;
+}
+
+impl<A: Actor> Tracked for WeakAddress<A> {
+    fn id(&self) -> ActorId {
+        WeakAddress::id(self)
+    }
+
+    fn info(&self) -> ActorInfo {
+        ActorInfo {
+            id: self.id(),
+            name: self.name().map(String::from),
+        }
+    }
+
+    fn is_stopped(&self) -> bool {
+        WeakAddress::is_stopped(self)
+    }
+
+    fn stop(&self) {
+        self.request_stop();
+    }
+
+    fn wait_for_stop(&self) -> BoxFuture<'_, StopReason> {
+        WeakAddress::wait_for_stop(self).boxed()
+    }
+}
+
+/// List of the actors tracked by the [`ActorSystem`].
+///
+/// It's shared with the [`Registry`] of the system, so the spawned services are tracked as well.
+#[derive(Clone, Default)]
+pub(crate) struct Tracker {
+    actors: Arc<Mutex<Vec<Arc<dyn Tracked>>>>,
+}
+
+impl std::fmt::Debug for Tracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tracker").finish_non_exhaustive()
+    }
+}
+
+impl Tracker {
+    pub(crate) fn track<A: Actor>(&self, address: &Address<A>) {
+        let mut actors = self.lock();
+        // Forget about the actors that are already stopped.
+        actors.retain(|actor| !actor.is_stopped());
+        // Actor that is already tracked keeps its place in the shutdown order.
+        if actors.iter().all(|actor| actor.id() != address.id()) {
+            actors.push(Arc::new(address.downgrade()));
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Arc<dyn Tracked>>> {
+        self.actors.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Description of an actor tracked by the [`ActorSystem`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActorInfo {
+    /// Identifier of the actor.
+    pub id: ActorId,
+    /// Name of the actor, if it was set via
+    /// [`ContextBuilder::name`](crate::context::ContextBuilder::name).
+    pub name: Option<String>,
+}
+
+/// Result of the [`ActorSystem::shutdown`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Actors that were stopped, in the order they were stopped.
+    pub stopped: Vec<ActorInfo>,
+    /// Actors that did not stop before the shutdown timeout expired.
+    pub timed_out: Vec<ActorInfo>,
+}
+
+impl ShutdownReport {
+    /// Returns `true` if all the actors were stopped in time.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.timed_out.is_empty()
+    }
+}
+
+/// `ActorSystem` spawns actors and keeps track of them, so that all of them can be
+/// stopped via [`ActorSystem::shutdown`].
+///
+/// System does not keep actors alive: it only stores [`WeakAddress`]es of them.
+/// Cloned `ActorSystem` objects refer to the same set of actors.
+///
+/// Each system has its own [`Registry`], and the services spawned by it are tracked
+/// by the system as well.
+///
+/// ## Examples
+///
+/// This example assumes that `messages` is used with `rt-tokio` feature enabled.
+///
+/// ```rust
+/// # use messages::prelude::*;
+/// # use std::time::Duration;
+/// struct Database;
+///
+/// impl Actor for Database {}
+///
+/// struct Server {
+///     database: Address<Database>,
+/// }
+///
+/// impl Actor for Server {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let system = ActorSystem::new();
+///     let database = system.spawn(Database);
+///     let server = system.spawn(Server { database });
+///     assert_eq!(system.actors().len(), 2);
+///
+///     // Server is stopped first, since it was spawned last.
+///     let report = system.shutdown(Duration::from_secs(5)).await;
+///     assert!(report.is_complete());
+///     assert_eq!(report.stopped[0].id, server.id());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ActorSystem {
+    tracker: Tracker,
+    registry: Arc<Registry>,
+}
+
+impl std::fmt::Debug for ActorSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ActorSystem")
+            .field("actors", &self.actors())
+            .field("registry", &self.registry)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ActorSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActorSystem {
+    /// Creates a new `ActorSystem` without actors.
+    #[must_use]
+    pub fn new() -> Self {
+        let tracker = Tracker::default();
+        Self {
+            registry: Arc::new(Registry::with_tracker(tracker.clone())),
+            tracker,
+        }
+    }
+
+    /// Returns the [`Registry`] of the system.
+    #[must_use]
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Spawns an actor and starts tracking it.
+    pub fn spawn<A: Actor>(&self, actor: A) -> Address<A> {
+        self.spawn_with_context(Context::new(), actor)
+    }
+
+    /// Same as [`ActorSystem::spawn`], but allows to provide a custom [`Context`]
+    /// (e.g. with a name or a custom mailbox configuration).
+    pub fn spawn_with_context<A: Actor>(&self, context: Context<A>, actor: A) -> Address<A> {
+        let address = context.spawn(actor);
+        self.track(&address);
+        address
+    }
+
+    /// Starts tracking an actor that was spawned elsewhere (e.g. by a
+    /// [`Supervisor`](crate::supervisor::Supervisor)).
+    ///
+    /// Tracked actors are stopped in the reverse order of tracking, so the actor
+    /// should be tracked after the actors it depends on.
+    pub fn track<A: Actor>(&self, address: &Address<A>) {
+        self.tracker.track(address);
+    }
+
+    /// Returns the actors that are not stopped yet, in the order they were spawned.
+    #[must_use]
+    pub fn actors(&self) -> Vec<ActorInfo> {
+        self.tracker
+            .lock()
+            .iter()
+            .filter(|actor| !actor.is_stopped())
+            .map(|actor| actor.info())
+            .collect()
+    }
+
+    /// Stops all the tracked actors.
+    ///
+    /// Actors are stopped one by one in the reverse spawn order, so actors that
+    /// depend on the ones spawned before them are stopped first. Each actor is
+    /// requested to stop via [`Address::stop`], so it can still refuse to stop.
+    ///
+    /// Returned report contains actors that did not stop before the `timeout` expired.
+    /// Once all the actors are handled, the [`Registry`] of the system is cleared.
+    /// Once the timeout is expired, the rest of the actors are still requested to stop,
+    /// but the system doesn't wait for them.
+    pub async fn shutdown(&self, timeout: Duration) -> ShutdownReport {
+        let deadline = Instant::now().checked_add(timeout);
+        let actors = std::mem::take(&mut *self.tracker.lock());
+
+        let mut report = ShutdownReport::default();
+        for actor in actors.iter().rev().filter(|actor| !actor.is_stopped()) {
+            actor.stop();
+            let stopped = if let Some(deadline) = deadline {
+                crate::runtime::timeout_at(deadline, actor.wait_for_stop())
+                    .await
+                    .is_some()
+            } else {
+                // Timeout is too big to be represented, so we can wait forever.
+                actor.wait_for_stop().await;
+                true
+            };
+            if stopped {
+                report.stopped.push(actor.info());
+            } else {
+                report.timed_out.push(actor.info());
+            }
+        }
+
+        // Actors that didn't stop in time are still tracked.
+        {
+            let mut tracked = self.tracker.lock();
+            let pending = actors.into_iter().filter(|actor| !actor.is_stopped());
+            let spawned_during_shutdown = std::mem::replace(&mut *tracked, pending.collect());
+            tracked.extend(spawned_during_shutdown);
+        }
+        // Services are stopped, so there is no point in keeping their addresses.
+        self.registry.clear().await;
+
+        report
+    }
+
+    /// Waits until the process receives SIGINT (Ctrl+C) or SIGTERM, and then
+    /// stops all the tracked actors via [`ActorSystem::shutdown`].
+    ///
+    /// SIGTERM is only handled on Unix platforms. This method is available only
+    /// when `messages` is built with `signal` feature enabled.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if signal handler can't be installed.
+    #[cfg(feature = "signal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signal")))]
+    pub async fn shutdown_on_signal(&self, timeout: Duration) -> ShutdownReport {
+        shutdown_signal().await;
+        self.shutdown(timeout).await
+    }
+}
+
+/// Waits until the process receives SIGINT or SIGTERM (the latter is only supported on Unix).
+#[cfg(feature = "signal")]
+async fn shutdown_signal() {
+    use async_signal::{Signal, Signals};
+    use futures::StreamExt;
+
+    #[cfg(unix)]
+    let signals = [Signal::Int, Signal::Term];
+    #[cfg(not(unix))]
+    let signals = [Signal::Int];
+
+    let mut signals = Signals::new(signals).expect("Unable to install signal handlers");
+    let _ = signals.next().await;
+}
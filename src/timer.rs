@@ -0,0 +1,66 @@
+//! Timers that deliver delayed and periodic notifications to an actor.
+//!
+//! Timers are created via [`Context::notify_later`] and [`Context::notify_interval`].
+//!
+//! [`Context::notify_later`]: crate::context::Context::notify_later
+//! [`Context::notify_interval`]: crate::context::Context::notify_interval
+
+use std::sync::{Mutex, PoisonError};
+
+use futures::future::AbortHandle;
+
+/// Handle to a timer scheduled by an actor.
+///
+/// Dropping the handle does not cancel the timer: it keeps running until it's
+/// either cancelled explicitly via [`TimerHandle::cancel`] or the actor is stopped.
+#[derive(Debug, Clone)]
+pub struct TimerHandle {
+    abort_handle: AbortHandle,
+}
+
+impl TimerHandle {
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    pub(crate) fn new(abort_handle: AbortHandle) -> Self {
+        Self { abort_handle }
+    }
+
+    /// Cancels the timer.
+    ///
+    /// Notifications that were already sent to the actor will still be processed.
+    pub fn cancel(&self) {
+        self.abort_handle.abort();
+    }
+
+    /// Returns `true` if the timer was cancelled or has already fired
+    /// (for one-shot timers).
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.abort_handle.is_aborted()
+    }
+}
+
+/// Collection of the timers and other background tasks (e.g. monitors)
+/// belonging to a single actor.
+#[derive(Debug, Default)]
+pub(crate) struct Timers {
+    handles: Mutex<Vec<AbortHandle>>,
+}
+
+impl Timers {
+    /// Adds a new timer to the collection, forgetting the finished ones.
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-async-std"))]
+    pub(crate) fn insert(&self, handle: AbortHandle) {
+        let mut handles = self.handles.lock().unwrap_or_else(PoisonError::into_inner);
+        handles.retain(|handle| !handle.is_aborted());
+        handles.push(handle);
+    }
+
+    /// Cancels all the timers.
+    pub(crate) fn cancel_all(&self) {
+        let handles =
+            std::mem::take(&mut *self.handles.lock().unwrap_or_else(PoisonError::into_inner));
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
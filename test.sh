@@ -15,6 +15,13 @@ echo "Running tests for async std"
 echo "---------------------------"
 cargo test --all-targets --no-default-features --features runtime-async-std || exit 1
 
+# Run tests with signal handling for both runtimes
+echo "-----------------------------------"
+echo "Running tests with signal handling"
+echo "-----------------------------------"
+cargo test --all-targets --no-default-features --features runtime-tokio,signal || exit 1
+cargo test --all-targets --no-default-features --features runtime-async-std,signal || exit 1
+
 # Run examples without any features
 echo "--------------------------------------"
 echo "Running tests with no runtime features"
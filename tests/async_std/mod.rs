@@ -0,0 +1,8 @@
+//! Tests using async-std as the main executor.
+//!
+//! These tests cover the parts of the crate that rely on the runtime backend
+//! (e.g. timers, timeouts and signal handling), the rest of the functionality is tested with tokio.
+
+mod system;
+mod timeout;
+mod timer;
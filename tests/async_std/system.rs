@@ -0,0 +1,61 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use messages::prelude::*;
+
+#[derive(Debug)]
+struct Recorder {
+    name: &'static str,
+    stopped: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl Actor for Recorder {
+    fn stopped(&mut self, _reason: StopReason) {
+        self.stopped.lock().unwrap().push(self.name);
+    }
+}
+
+#[async_std::test]
+async fn shutdown_in_reverse_order() {
+    let system = ActorSystem::new();
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+    let _addresses: Vec<_> = ["database", "cache", "server"]
+        .iter()
+        .map(|&name| {
+            system.spawn(Recorder {
+                name,
+                stopped: stopped.clone(),
+            })
+        })
+        .collect();
+
+    let report = system.shutdown(Duration::from_secs(5)).await;
+    assert!(report.is_complete());
+    assert_eq!(*stopped.lock().unwrap(), ["server", "cache", "database"]);
+}
+
+#[cfg(all(unix, feature = "signal"))]
+#[async_std::test]
+async fn shutdown_on_signal() {
+    let system = ActorSystem::new();
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+    let _address = system.spawn(Recorder {
+        name: "recorder",
+        stopped: stopped.clone(),
+    });
+
+    let (report, ()) = futures::join!(system.shutdown_on_signal(Duration::from_secs(5)), async {
+        // Give the system some time to install the signal handler.
+        async_std::task::sleep(Duration::from_millis(100)).await;
+        let status = std::process::Command::new("kill")
+            .arg("-TERM")
+            .arg(std::process::id().to_string())
+            .status()
+            .unwrap();
+        assert!(status.success());
+    });
+    assert!(report.is_complete());
+    assert_eq!(*stopped.lock().unwrap(), ["recorder"]);
+}
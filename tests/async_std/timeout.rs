@@ -0,0 +1,74 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use messages::prelude::*;
+
+#[derive(Debug, Default)]
+struct SleepyActor {
+    handled: Arc<AtomicUsize>,
+}
+
+impl Actor for SleepyActor {}
+
+#[async_trait]
+impl Handler<Duration> for SleepyActor {
+    type Result = ();
+
+    async fn handle(&mut self, input: Duration, _: &Context<Self>) {
+        async_std::task::sleep(input).await;
+        self.handled.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[async_std::test]
+async fn send_timeout() {
+    let mut address = SleepyActor::default().spawn();
+
+    let result = address
+        .send_timeout(Duration::from_millis(200), Duration::from_millis(10))
+        .await;
+    assert!(matches!(result, Err(SendError::Timeout)));
+
+    // Actor must still be functional after the timeout.
+    address
+        .send_timeout(Duration::from_millis(0), Duration::from_secs(1))
+        .await
+        .expect("Message was not processed in time");
+
+    address.stop().await;
+    address.wait_for_stop().await;
+}
+
+#[async_std::test]
+async fn expired_message_is_skipped() {
+    let handled = Arc::new(AtomicUsize::new(0));
+    let mut address = SleepyActor {
+        handled: handled.clone(),
+    }
+    .spawn();
+
+    // Keep the actor busy, so that the next message expires in the mailbox.
+    let mut busy_address = address.clone();
+    let busy =
+        async_std::task::spawn(async move { busy_address.send(Duration::from_millis(100)).await });
+    async_std::task::sleep(Duration::from_millis(10)).await;
+
+    let deadline = Instant::now() + Duration::from_millis(10);
+    let result = address
+        .send_with_deadline(Duration::from_millis(0), deadline)
+        .await;
+    assert!(matches!(result, Err(SendError::Timeout)));
+
+    busy.await.unwrap();
+    // Make sure that the expired message was not processed.
+    address.send(Duration::from_millis(0)).await.unwrap();
+    assert_eq!(handled.load(Ordering::SeqCst), 2);
+
+    address.stop().await;
+    address.wait_for_stop().await;
+}
@@ -0,0 +1,102 @@
+use std::{sync::Arc, time::Duration};
+
+use messages::{context::StopMode, prelude::*};
+use tokio::sync::Notify;
+
+#[derive(Debug, Default)]
+struct Worker {
+    handled: usize,
+    busy: Arc<Notify>,
+}
+
+impl Actor for Worker {}
+
+#[async_trait]
+impl Handler<Duration> for Worker {
+    type Result = usize;
+
+    async fn handle(&mut self, input: Duration, _: &Context<Self>) -> usize {
+        self.busy.notify_one();
+        tokio::time::sleep(input).await;
+        self.handled += 1;
+        self.handled
+    }
+}
+
+#[tokio::test]
+async fn drain_handles_queued_messages() {
+    let context = Context::builder()
+        .stop_mode(StopMode::Drain(Duration::from_secs(10)))
+        .build();
+    let mut address = context.address();
+
+    let responses: Vec<_> = (0..3)
+        .map(|_| address.try_send(Duration::from_millis(1)).unwrap())
+        .collect();
+    address.stop().await;
+    let (_, handle) = context.spawn_with_handle(Worker::default());
+
+    for (expected, response) in (1..).zip(responses) {
+        assert_eq!(response.await.unwrap(), expected);
+    }
+    let outcome = handle.await;
+    assert_eq!(outcome.actor.handled, 3);
+    assert_eq!(outcome.reason, StopReason::Requested);
+}
+
+#[tokio::test]
+async fn drain_timeout_rejects_the_rest() {
+    let context = Context::builder()
+        .stop_mode(StopMode::Drain(Duration::from_millis(50)))
+        .build();
+    let mut address = context.address();
+    let busy = Arc::new(Notify::new());
+
+    let responses: Vec<_> = [10, 1000, 10]
+        .iter()
+        .map(|&millis| address.try_send(Duration::from_millis(millis)).unwrap())
+        .collect();
+    address.stop().await;
+    let started = std::time::Instant::now();
+    let (_, handle) = context.spawn_with_handle(Worker {
+        busy: busy.clone(),
+        ..Worker::default()
+    });
+
+    // Mailbox is closed while the queued messages are handled.
+    busy.notified().await;
+    assert!(matches!(
+        address.send(Duration::from_millis(0)).await,
+        Err(SendError::ActorStopping)
+    ));
+
+    let mut responses = responses.into_iter();
+    assert_eq!(responses.next().unwrap().await.unwrap(), 1);
+    // Slow handler is interrupted once the timeout expires, and the rest is rejected.
+    for response in responses {
+        assert!(matches!(response.await, Err(SendError::ActorStopping)));
+    }
+    assert_eq!(handle.await.actor.handled, 1);
+    assert!(started.elapsed() < Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn reject_mode() {
+    let context = Context::builder().stop_mode(StopMode::Reject).build();
+    let mut address = context.address();
+
+    let responses: Vec<_> = (0..2)
+        .map(|_| address.try_send(Duration::from_millis(1)).unwrap())
+        .collect();
+    address.stop().await;
+    let (_, handle) = context.spawn_with_handle(Worker::default());
+
+    for response in responses {
+        assert!(matches!(response.await, Err(SendError::ActorStopping)));
+    }
+    assert_eq!(handle.await.actor.handled, 0);
+    assert!(matches!(
+        address.send(Duration::from_millis(0)).await,
+        Err(SendError::ReceiverDisconnected)
+    ));
+}
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use messages::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Price(u32);
+
+#[derive(Debug, Clone, PartialEq)]
+struct Alert(&'static str);
+
+#[derive(Debug, Default)]
+struct Listener {
+    prices: Vec<u32>,
+    alerts: Vec<&'static str>,
+}
+
+impl Actor for Listener {}
+
+#[async_trait]
+impl Notifiable<Price> for Listener {
+    async fn notify(&mut self, input: Price, _: &Context<Self>) {
+        self.prices.push(input.0);
+    }
+}
+
+#[async_trait]
+impl Notifiable<Alert> for Listener {
+    async fn notify(&mut self, input: Alert, _: &Context<Self>) {
+        self.alerts.push(input.0);
+    }
+}
+
+#[derive(Debug)]
+struct Received;
+
+#[async_trait]
+impl Handler<Received> for Listener {
+    type Result = (Vec<u32>, Vec<&'static str>);
+
+    async fn handle(&mut self, _: Received, _: &Context<Self>) -> Self::Result {
+        (self.prices.clone(), self.alerts.clone())
+    }
+}
+
+/// Actor that is slow to process prices.
+#[derive(Debug)]
+struct Sleeper;
+
+impl Actor for Sleeper {}
+
+#[async_trait]
+impl Notifiable<Price> for Sleeper {
+    async fn notify(&mut self, _input: Price, _: &Context<Self>) {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+#[tokio::test]
+async fn publish_to_subscribers() {
+    let registry = Registry::new();
+    let mut bus: Address<EventBus> = registry.service().await;
+
+    let mut all = Listener::default().spawn();
+    let mut expensive = Listener::default().spawn();
+    bus.subscribe::<Price, _>(&all).await.unwrap();
+    bus.subscribe::<Alert, _>(&all).await.unwrap();
+    bus.subscribe_filtered(&expensive, |price: &Price| price.0 > 100)
+        .await
+        .unwrap();
+
+    bus.publish(Price(10)).await.unwrap();
+    bus.publish(Alert("halt")).await.unwrap();
+    bus.publish(Price(200)).await.unwrap();
+    // Once the bus responds, all the events are delivered to the mailboxes.
+    assert_eq!(bus.subscribers::<Price>().await.unwrap(), 2);
+
+    assert_eq!(
+        all.send(Received).await.unwrap(),
+        (vec![10, 200], vec!["halt"])
+    );
+    assert_eq!(expensive.send(Received).await.unwrap(), (vec![200], vec![]));
+}
+
+#[tokio::test]
+async fn unsubscribe() {
+    let mut bus = EventBus::new().spawn();
+    let mut first = Listener::default().spawn();
+    let mut second = Listener::default().spawn();
+    bus.subscribe::<Price, _>(&first).await.unwrap();
+    // Repeated subscription replaces the previous one.
+    bus.subscribe::<Price, _>(&first).await.unwrap();
+    bus.subscribe::<Price, _>(&second).await.unwrap();
+    assert_eq!(bus.subscribers::<Price>().await.unwrap(), 2);
+
+    bus.unsubscribe::<Price, _>(&first).await.unwrap();
+    bus.publish(Price(1)).await.unwrap();
+    assert_eq!(bus.subscribers::<Price>().await.unwrap(), 1);
+    assert_eq!(first.send(Received).await.unwrap().0, Vec::<u32>::new());
+    assert_eq!(second.send(Received).await.unwrap().0, [1]);
+
+    // Stopped subscribers are unsubscribed automatically.
+    second.stop().await;
+    second.wait_for_stop().await;
+    assert_eq!(bus.subscribers::<Price>().await.unwrap(), 0);
+
+    // Bus doesn't keep subscribers alive.
+    bus.subscribe::<Price, _>(&first).await.unwrap();
+    let weak = first.downgrade();
+    drop(first);
+    weak.wait_for_stop().await;
+    bus.publish(Price(2)).await.unwrap();
+    assert_eq!(bus.subscribers::<Price>().await.unwrap(), 0);
+}
+
+/// Publishes several events to a slow and a fast subscribers.
+async fn flood(
+    policy: SlowSubscriberPolicy,
+) -> (Address<EventBus>, Address<Sleeper>, Address<Listener>) {
+    let mut bus = EventBus::new().with_policy(policy).spawn();
+    let sleeper = Context::with_capacity(1).spawn(Sleeper);
+    let listener = Listener::default().spawn();
+    bus.subscribe::<Price, _>(&sleeper).await.unwrap();
+    bus.subscribe::<Price, _>(&listener).await.unwrap();
+    for price in 0..5 {
+        bus.publish(Price(price)).await.unwrap();
+    }
+    (bus, sleeper, listener)
+}
+
+#[tokio::test]
+async fn slow_subscriber_dropped_events() {
+    let (mut bus, _sleeper, mut listener) = flood(SlowSubscriberPolicy::Drop).await;
+    assert_eq!(bus.subscribers::<Price>().await.unwrap(), 2);
+    assert_eq!(listener.send(Received).await.unwrap().0, [0, 1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn slow_subscriber_unsubscribed() {
+    let (mut bus, _sleeper, mut listener) = flood(SlowSubscriberPolicy::Unsubscribe).await;
+    assert_eq!(bus.subscribers::<Price>().await.unwrap(), 1);
+    assert_eq!(listener.send(Received).await.unwrap().0, [0, 1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn slow_subscriber_blocks() {
+    let (mut bus, _sleeper, mut listener) = flood(SlowSubscriberPolicy::Block).await;
+    assert_eq!(bus.subscribers::<Price>().await.unwrap(), 2);
+    assert_eq!(listener.send(Received).await.unwrap().0, [0, 1, 2, 3, 4]);
+}
@@ -0,0 +1,126 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use messages::prelude::*;
+
+#[derive(Debug, Clone)]
+struct Event(usize);
+
+#[derive(Debug, Default)]
+struct Summer {
+    total: Arc<AtomicUsize>,
+}
+
+impl Actor for Summer {}
+
+#[async_trait]
+impl Notifiable<Event> for Summer {
+    async fn notify(&mut self, input: Event, _: &Context<Self>) {
+        self.total.fetch_add(input.0, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl Handler<Event> for Summer {
+    type Result = usize;
+
+    async fn handle(&mut self, input: Event, _: &Context<Self>) -> usize {
+        self.total.fetch_add(input.0, Ordering::SeqCst) + input.0
+    }
+}
+
+/// Actor that is slow to process events.
+#[derive(Debug)]
+struct Sleeper;
+
+impl Actor for Sleeper {}
+
+#[async_trait]
+impl Notifiable<Event> for Sleeper {
+    async fn notify(&mut self, _input: Event, _: &Context<Self>) {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+#[async_trait]
+impl Handler<Event> for Sleeper {
+    type Result = usize;
+
+    async fn handle(&mut self, _input: Event, _: &Context<Self>) -> usize {
+        0
+    }
+}
+
+#[tokio::test]
+async fn send_to_group() {
+    let total = Arc::new(AtomicUsize::new(0));
+    let mut group: AddressGroup<Event, usize> = AddressGroup::new();
+    group.push(
+        Summer {
+            total: total.clone(),
+        }
+        .spawn(),
+    );
+    group.push(Sleeper.spawn());
+
+    let results = group.send(Event(2)).await;
+    assert_eq!(
+        results.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+        [2, 0]
+    );
+    assert_eq!(total.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn broadcast_is_concurrent() {
+    let total = Arc::new(AtomicUsize::new(0));
+    let summer = Summer {
+        total: total.clone(),
+    }
+    .spawn();
+    let sleeper = Context::with_capacity(1).spawn(Sleeper);
+
+    let mut broadcast = Broadcast::new();
+    broadcast.push(sleeper.clone());
+    broadcast.push(summer.clone());
+
+    // Keep the mailbox of the slow actor full.
+    broadcast.notify(Event(1)).await;
+    broadcast.notify(Event(1)).await;
+
+    let notified = tokio::spawn(async move { broadcast.notify(Event(1)).await });
+    // Fast actor receives the event while the broadcast waits for the slow one.
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(total.load(Ordering::SeqCst), 3);
+    assert!(!notified.is_finished());
+
+    let results = notified.await.unwrap();
+    assert!(results.iter().all(Result::is_ok));
+}
+
+#[tokio::test]
+async fn disconnected_members_are_pruned() {
+    let mut first = Summer::default().spawn();
+    let second = Summer::default().spawn();
+    let mut broadcast: Broadcast<Event> = vec![first.clone(), second.clone()].into_iter().collect();
+    assert_eq!(broadcast.len(), 2);
+
+    first.stop().await;
+    first.wait_for_stop().await;
+
+    let results = broadcast.notify(Event(1)).await;
+    assert!(matches!(
+        results.as_slice(),
+        [Err(SendError::ReceiverDisconnected), Ok(())]
+    ));
+    assert_eq!(broadcast.len(), 1);
+
+    drop(second);
+    // Group keeps the remaining actor alive.
+    assert!(broadcast.notify(Event(1)).await[0].is_ok());
+}
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use messages::prelude::*;
+
+use super::PingActor;
+
+#[tokio::test]
+async fn addresses_are_identified_by_actor() {
+    let context = Context::new();
+    let id = context.id();
+    let first = context.spawn(PingActor);
+    let second = PingActor.spawn();
+
+    assert_eq!(first.id(), id);
+    assert_ne!(first.id(), second.id());
+    assert_eq!(first.clone(), first);
+    assert_eq!(first.downgrade().upgrade().unwrap(), first);
+    assert_ne!(first, second);
+
+    #[allow(clippy::mutable_key_type)] // Hash is based on the identifier only.
+    let set: HashSet<_> = vec![first.clone(), second.clone(), first.clone()]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[tokio::test]
+async fn named_actor() {
+    let context = Context::builder().name("ping").capacity(4).build();
+    assert_eq!(context.name(), Some("ping"));
+
+    let address = context.spawn(PingActor);
+    assert_eq!(address.name(), Some("ping"));
+    assert_eq!(address.downgrade().name(), Some("ping"));
+    assert!(PingActor.spawn().name().is_none());
+
+    let debug = format!("{:?}", address);
+    assert!(debug.contains(&format!("{:?}", address.id())), "{}", debug);
+    assert!(debug.contains("\"ping\""), "{}", debug);
+}
+
+#[tokio::test]
+async fn recipient_identity() {
+    let address = Context::builder().name("ping").build().spawn(PingActor);
+    let recipient = address.recipient::<u8>();
+    assert_eq!(recipient.id(), address.id());
+    assert_eq!(recipient.name(), Some("ping"));
+
+    let debug = format!("{:?}", recipient);
+    assert!(debug.contains(&format!("{:?}", address.id())), "{}", debug);
+    assert!(debug.contains("\"ping\""), "{}", debug);
+}
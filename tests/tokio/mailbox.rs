@@ -0,0 +1,164 @@
+use messages::{
+    mailbox::{MailboxConfig, MailboxPolicy, MailboxStats},
+    prelude::*,
+};
+
+#[derive(Debug, Default)]
+struct Recorder {
+    received: Vec<u32>,
+}
+
+impl Actor for Recorder {}
+
+#[async_trait]
+impl Notifiable<u32> for Recorder {
+    async fn notify(&mut self, input: u32, _: &Context<Self>) {
+        self.received.push(input);
+    }
+}
+
+#[async_trait]
+impl Handler<()> for Recorder {
+    type Result = Vec<u32>;
+
+    async fn handle(&mut self, _input: (), _: &Context<Self>) -> Vec<u32> {
+        self.received.clone()
+    }
+}
+
+/// Waits until actor receives all the messages, so that the mailbox policy
+/// is not applied to the next message.
+async fn wait_for_empty_mailbox(address: &Address<Recorder>) {
+    while address.mailbox_len() > 0 {
+        tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+    }
+}
+
+/// Fills the mailbox of a not yet started actor with notifications, runs the actor and
+/// returns the notifications it has received.
+async fn fill_and_run(config: MailboxConfig, messages: u32) -> (Vec<u32>, MailboxStats) {
+    let context: Context<Recorder> = Context::with_config(config);
+    let mut address = context.address();
+
+    for message in 0..messages {
+        // Mailbox policy is applied even if the sender is not waiting.
+        let _ = address.try_notify(message);
+    }
+    let stats = address.mailbox_stats();
+
+    let handle = tokio::spawn(context.run(Recorder::default()));
+    wait_for_empty_mailbox(&address).await;
+    let received = address.send(()).await.unwrap();
+    address.stop().await;
+    handle.await.unwrap();
+
+    (received, stats)
+}
+
+#[tokio::test]
+async fn drop_newest() {
+    let config = MailboxConfig::new(2).with_policy(MailboxPolicy::DropNewest);
+    let (received, stats) = fill_and_run(config, 4).await;
+    assert_eq!(received, vec![0, 1]);
+    assert_eq!(stats.dropped, 2);
+}
+
+#[tokio::test]
+async fn drop_oldest() {
+    let config = MailboxConfig::new(2).with_policy(MailboxPolicy::DropOldest);
+    let (received, stats) = fill_and_run(config, 4).await;
+    assert_eq!(received, vec![2, 3]);
+    assert_eq!(stats.dropped, 2);
+}
+
+#[tokio::test]
+async fn drop_oldest_rejects_evicted_request() {
+    let config = MailboxConfig::new(1).with_policy(MailboxPolicy::DropOldest);
+    let context: Context<Recorder> = Context::with_config(config);
+    let mut address = context.address();
+
+    let mut sender = address.clone();
+    let evicted = tokio::spawn(async move { sender.send(()).await });
+    while address.mailbox_len() == 0 {
+        tokio::task::yield_now().await;
+    }
+    address.try_notify(1).unwrap();
+
+    // Sender of the evicted request can tell that the actor is still running.
+    assert!(matches!(
+        evicted.await.unwrap(),
+        Err(SendError::MailboxFull(()))
+    ));
+    assert_eq!(address.mailbox_stats().dropped, 1);
+
+    let handle = tokio::spawn(context.run(Recorder::default()));
+    wait_for_empty_mailbox(&address).await;
+    assert_eq!(address.send(()).await.unwrap(), vec![1]);
+    address.stop().await;
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn unbounded() {
+    let (received, stats) = fill_and_run(MailboxConfig::unbounded(), 1000).await;
+    assert_eq!(received.len(), 1000);
+    assert_eq!(stats, MailboxStats::default());
+}
+
+#[tokio::test]
+async fn reject() {
+    let config = MailboxConfig::new(1).with_policy(MailboxPolicy::Reject);
+    let context: Context<Recorder> = Context::with_config(config);
+    let mut address = context.address();
+
+    address.notify(1).await.unwrap();
+    // Sender does not wait for the free space.
+    assert!(matches!(
+        address.notify(2).await,
+        Err(SendError::MailboxFull(()))
+    ));
+    assert!(matches!(
+        address.try_notify(3),
+        Err(SendError::MailboxFull(3))
+    ));
+    assert_eq!(address.mailbox_stats().rejected, 2);
+
+    let handle = tokio::spawn(context.run(Recorder::default()));
+    wait_for_empty_mailbox(&address).await;
+    assert_eq!(address.send(()).await.unwrap(), vec![1]);
+    address.stop().await;
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn priority_lane_is_drained_first() {
+    let context: Context<Recorder> = Context::with_capacity(8);
+    let mut address = context.address();
+
+    address.notify(1).await.unwrap();
+    address.notify(2).await.unwrap();
+    address.notify_priority(100).unwrap();
+
+    let handle = tokio::spawn(context.run(Recorder::default()));
+    let received = address.send(()).await.unwrap();
+    assert_eq!(received, vec![100, 1, 2]);
+
+    address.stop().await;
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn stop_is_not_queued_behind_messages() {
+    let context: Context<Recorder> = Context::with_capacity(8);
+    let mut address = context.address();
+
+    for message in 0..8 {
+        address.notify(message).await.unwrap();
+    }
+    // Mailbox is full, but stop request must still be delivered without waiting.
+    address.stop().await;
+
+    tokio::spawn(context.run(Recorder::default()));
+    address.wait_for_stop().await;
+    assert!(!address.connected());
+}
@@ -6,11 +6,27 @@ use std::sync::{
 };
 
 use messages::{
-    actor::ActorAction,
-    prelude::{async_trait, Actor, Context, Handler, RuntimeActorExt},
+    actor::{ActorAction, StopReason},
+    prelude::{async_trait, Actor, Context, Handler, RuntimeActorExt, SendError},
 };
 
+mod drain;
+mod event_bus;
+mod group;
+mod identity;
+mod mailbox;
+mod monitor;
+mod panic;
+mod pool;
+mod recipient;
 mod registry;
+mod stop;
+mod stream;
+mod supervisor;
+mod system;
+mod timeout;
+mod timer;
+mod weak;
 
 #[derive(Debug)]
 struct PingActor;
@@ -50,12 +66,12 @@ impl Actor for WorkflowActor {
         self.state.started.store(true, Ordering::SeqCst);
     }
 
-    async fn stopping(&mut self) -> ActorAction {
+    async fn stopping(&mut self, _reason: StopReason) -> ActorAction {
         self.state.stopping.store(true, Ordering::SeqCst);
         ActorAction::Stop
     }
 
-    fn stopped(&mut self) {
+    fn stopped(&mut self, _reason: StopReason) {
         self.state.stopped.store(true, Ordering::SeqCst);
     }
 }
@@ -72,7 +88,7 @@ struct Unstoppable;
 
 #[async_trait]
 impl Actor for Unstoppable {
-    async fn stopping(&mut self) -> ActorAction {
+    async fn stopping(&mut self, _reason: StopReason) -> ActorAction {
         ActorAction::KeepRunning
     }
 }
@@ -138,3 +154,64 @@ async fn unstoppable() {
         .await
         .expect("Actor did not process the message");
 }
+
+#[tokio::test]
+async fn try_send_full_mailbox() {
+    let mailbox: Context<PingActor> = Context::with_capacity(1);
+    let mut address = mailbox.address();
+
+    // Actor is not running yet, so the first message occupies the whole mailbox.
+    let response = address.try_send(1).expect("Mailbox is empty");
+    match address.try_send(2) {
+        Err(SendError::MailboxFull(message)) => assert_eq!(message, 2),
+        Ok(_) => panic!("Message was sent to the full mailbox"),
+        Err(err) => panic!("Unexpected error: {}", err),
+    }
+
+    let future = tokio::spawn(mailbox.run(PingActor));
+    assert_eq!(response.await.unwrap(), 1);
+
+    address.stop().await;
+    assert!(future.await.is_ok());
+}
+
+#[derive(Debug, Default)]
+struct Counter(u32);
+
+impl Actor for Counter {}
+
+#[async_trait]
+impl Handler<u32> for Counter {
+    type Result = u32;
+
+    async fn handle(&mut self, input: u32, _: &Context<Self>) -> u32 {
+        self.0 += input;
+        self.0
+    }
+}
+
+#[tokio::test]
+async fn run_returns_final_state() {
+    let context = Context::new();
+    let mut address = context.address();
+    let future = tokio::spawn(context.run(Counter::default()));
+
+    address.send(2).await.unwrap();
+    address.send(3).await.unwrap();
+    address.stop().await;
+
+    let outcome = future.await.unwrap();
+    assert_eq!(outcome.actor.0, 5);
+    assert_eq!(outcome.reason, StopReason::Requested);
+}
+
+#[tokio::test]
+async fn spawn_with_handle() {
+    let (mut address, handle) = Counter::default().spawn_with_handle();
+    address.send(42).await.unwrap();
+    drop(address);
+
+    let outcome = handle.await;
+    assert_eq!(outcome.actor.0, 42);
+    assert_eq!(outcome.reason, StopReason::Disconnected);
+}
@@ -0,0 +1,92 @@
+use messages::prelude::*;
+
+#[derive(Debug)]
+struct Worker;
+
+impl Actor for Worker {}
+
+#[async_trait]
+impl Notifiable<()> for Worker {
+    async fn notify(&mut self, _input: (), _: &Context<Self>) {
+        panic!("Worker failed");
+    }
+}
+
+/// Actor that reports received `Terminated` notifications.
+#[derive(Debug)]
+struct Watcher {
+    events: async_channel::Sender<Terminated>,
+}
+
+impl Actor for Watcher {}
+
+#[async_trait]
+impl Notifiable<Terminated> for Watcher {
+    async fn notify(&mut self, input: Terminated, _: &Context<Self>) {
+        self.events.send(input).await.unwrap();
+    }
+}
+
+#[async_trait]
+impl Notifiable<Address<Worker>> for Watcher {
+    async fn notify(&mut self, input: Address<Worker>, context: &Context<Self>) {
+        context.monitor(&input);
+    }
+}
+
+#[async_trait]
+impl Handler<Address<Worker>> for Worker {
+    type Result = ();
+
+    async fn handle(&mut self, input: Address<Worker>, context: &Context<Self>) {
+        context.link(&input);
+    }
+}
+
+#[tokio::test]
+async fn monitor() {
+    let (sender, events) = async_channel::unbounded();
+    let mut watcher = Watcher { events: sender }.spawn();
+
+    let mut first = Worker.spawn();
+    let mut second = Worker.spawn();
+    watcher.notify(first.clone()).await.unwrap();
+    watcher.notify(second.clone()).await.unwrap();
+
+    first.stop().await;
+    let terminated = events.recv().await.unwrap();
+    assert_eq!(terminated.id, first.id());
+    assert_eq!(terminated.reason, StopReason::Requested);
+
+    second.notify(()).await.unwrap();
+    let terminated = events.recv().await.unwrap();
+    assert_eq!(terminated.id, second.id());
+    assert_eq!(terminated.reason, StopReason::Panicked);
+    assert_ne!(first.id(), second.id());
+
+    watcher.stop().await;
+    watcher.wait_for_stop().await;
+}
+
+#[tokio::test]
+async fn link() {
+    let mut first = Worker.spawn();
+    let mut second = Worker.spawn();
+    first.send(second.clone()).await.unwrap();
+
+    // Failure of one actor stops the other one.
+    second.notify(()).await.unwrap();
+    assert_eq!(second.wait_for_stop().await, StopReason::Panicked);
+    assert_eq!(first.wait_for_stop().await, StopReason::Linked);
+
+    // Graceful stop is not propagated.
+    let mut first = Worker.spawn();
+    let mut second = Worker.spawn();
+    first.send(second.clone()).await.unwrap();
+    second.stop().await;
+    assert_eq!(second.wait_for_stop().await, StopReason::Requested);
+    assert!(first.connected());
+
+    first.stop().await;
+    first.wait_for_stop().await;
+}
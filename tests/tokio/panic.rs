@@ -0,0 +1,127 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use messages::{context::StopMode, prelude::*};
+
+#[derive(Debug)]
+struct Fragile {
+    action: PanicAction,
+    started: Arc<AtomicUsize>,
+    panics: Arc<AtomicUsize>,
+}
+
+impl Fragile {
+    fn new(action: PanicAction) -> Self {
+        Self {
+            action,
+            started: Arc::default(),
+            panics: Arc::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl Actor for Fragile {
+    async fn started(&mut self) {
+        self.started.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn on_panic(&mut self, message: &str) -> PanicAction {
+        assert_eq!(message, "Invalid input");
+        self.panics.fetch_add(1, Ordering::SeqCst);
+        self.action
+    }
+}
+
+#[async_trait]
+impl Handler<u32> for Fragile {
+    type Result = u32;
+
+    async fn handle(&mut self, input: u32, _: &Context<Self>) -> u32 {
+        assert!(input != 0, "Invalid input");
+        input
+    }
+}
+
+#[async_trait]
+impl Notifiable<u32> for Fragile {
+    async fn notify(&mut self, input: u32, _: &Context<Self>) {
+        assert!(input != 0, "Invalid input");
+    }
+}
+
+#[tokio::test]
+async fn panic_continue() {
+    let actor = Fragile::new(PanicAction::Continue);
+    let (started, panics) = (actor.started.clone(), actor.panics.clone());
+    let mut address = actor.spawn();
+
+    assert!(matches!(
+        address.send(0).await,
+        Err(SendError::HandlerPanicked)
+    ));
+    address.notify(0).await.unwrap();
+    assert_eq!(address.send(1).await.unwrap(), 1);
+    assert_eq!(panics.load(Ordering::SeqCst), 2);
+    assert_eq!(started.load(Ordering::SeqCst), 1);
+
+    address.stop().await;
+    address.wait_for_stop().await;
+}
+
+#[tokio::test]
+async fn panic_reinitialize() {
+    let actor = Fragile::new(PanicAction::Reinitialize);
+    let started = actor.started.clone();
+    let mut address = actor.spawn();
+
+    assert!(matches!(
+        address.send(0).await,
+        Err(SendError::HandlerPanicked)
+    ));
+    assert_eq!(address.send(1).await.unwrap(), 1);
+    assert_eq!(started.load(Ordering::SeqCst), 2);
+
+    address.stop().await;
+    address.wait_for_stop().await;
+}
+
+#[tokio::test]
+async fn panic_stop() {
+    let mut address = Fragile::new(PanicAction::Stop).spawn();
+
+    assert!(matches!(
+        address.send(0).await,
+        Err(SendError::HandlerPanicked)
+    ));
+    assert_eq!(address.wait_for_stop().await, StopReason::Panicked);
+    assert!(matches!(
+        address.send(1).await,
+        Err(SendError::ReceiverDisconnected)
+    ));
+}
+
+#[tokio::test]
+async fn panic_reinitialize_while_draining() {
+    let actor = Fragile::new(PanicAction::Reinitialize);
+    let (started, panics) = (actor.started.clone(), actor.panics.clone());
+    let context = Context::builder()
+        .stop_mode(StopMode::Drain(Duration::from_secs(10)))
+        .build();
+    let mut address = context.address();
+
+    // Stop request is processed first, so the notification is handled while draining.
+    address.notify(0).await.unwrap();
+    address.stop().await;
+    let handle = tokio::spawn(context.run(actor));
+
+    assert_eq!(address.wait_for_stop().await, StopReason::Requested);
+    handle.await.unwrap();
+    assert_eq!(panics.load(Ordering::SeqCst), 1);
+    assert_eq!(started.load(Ordering::SeqCst), 2);
+}
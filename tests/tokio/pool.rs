@@ -0,0 +1,178 @@
+use std::{collections::HashSet, time::Duration};
+
+use messages::prelude::*;
+
+#[derive(Debug)]
+struct Worker {
+    id: usize,
+    handled: usize,
+}
+
+impl Actor for Worker {}
+
+#[derive(Debug)]
+struct WhoAmI;
+
+#[async_trait]
+impl Handler<WhoAmI> for Worker {
+    type Result = (usize, usize);
+
+    async fn handle(&mut self, _input: WhoAmI, _: &Context<Self>) -> (usize, usize) {
+        self.handled += 1;
+        (self.id, self.handled)
+    }
+}
+
+#[derive(Debug)]
+struct Sleep(Duration);
+
+#[async_trait]
+impl Notifiable<Sleep> for Worker {
+    async fn notify(&mut self, input: Sleep, _: &Context<Self>) {
+        tokio::time::sleep(input.0).await;
+    }
+}
+
+#[derive(Debug)]
+struct SlowWhoAmI(Duration);
+
+#[async_trait]
+impl Handler<SlowWhoAmI> for Worker {
+    type Result = usize;
+
+    async fn handle(&mut self, input: SlowWhoAmI, _: &Context<Self>) -> usize {
+        tokio::time::sleep(input.0).await;
+        self.id
+    }
+}
+
+fn pool(size: usize, router: Router) -> Pool<Worker> {
+    let mut next_id = 0;
+    Pool::new(size, move |_| {
+        next_id += 1;
+        Worker {
+            id: next_id,
+            handled: 0,
+        }
+    })
+    .with_router(router)
+}
+
+#[tokio::test]
+async fn round_robin() {
+    let pool = pool(3, Router::RoundRobin);
+    let mut ids = Vec::new();
+    for _ in 0..6 {
+        ids.push(pool.send(WhoAmI).await.unwrap().0);
+    }
+    assert_eq!(ids, [1, 2, 3, 1, 2, 3]);
+}
+
+#[tokio::test]
+async fn random() {
+    let pool = pool(3, Router::Random);
+    let mut ids = HashSet::new();
+    for _ in 0..100 {
+        ids.insert(pool.send(WhoAmI).await.unwrap().0);
+    }
+    assert_eq!(ids, [1, 2, 3].iter().copied().collect());
+}
+
+#[tokio::test]
+async fn shortest_mailbox() {
+    let pool = pool(2, Router::ShortestMailbox);
+    // Keep the first actor busy, so that messages wait in its mailbox.
+    let mut busy = pool.addresses()[0].clone();
+    for _ in 0..3 {
+        busy.notify(Sleep(Duration::from_millis(100)))
+            .await
+            .unwrap();
+    }
+
+    for _ in 0..3 {
+        assert_eq!(pool.send(WhoAmI).await.unwrap().0, 2);
+    }
+}
+
+#[tokio::test]
+async fn consistent_hash() {
+    let pool = pool(4, Router::ConsistentHash);
+    let mut owners = Vec::new();
+    for key in 0..20_usize {
+        let (id, _) = pool.send_by_key(&key, WhoAmI).await.unwrap();
+        // Same key is always routed to the same actor.
+        assert_eq!(pool.send_by_key(&key, WhoAmI).await.unwrap().0, id);
+        owners.push(id);
+    }
+
+    // Keys of the remaining actors are not moved.
+    pool.resize(3);
+    for (key, &owner) in owners.iter().enumerate() {
+        let (id, _) = pool.send_by_key(&key, WhoAmI).await.unwrap();
+        if owner != 4 {
+            assert_eq!(id, owner);
+        }
+    }
+}
+
+#[tokio::test]
+async fn resize() {
+    let pool = pool(2, Router::RoundRobin);
+    pool.resize(4);
+    assert_eq!(pool.size(), 4);
+    let mut ids = HashSet::new();
+    for _ in 0..4 {
+        ids.insert(pool.send(WhoAmI).await.unwrap().0);
+    }
+    assert_eq!(ids, [1, 2, 3, 4].iter().copied().collect());
+
+    let removed = pool.addresses()[3].downgrade();
+    pool.resize(1);
+    removed.wait_for_stop().await;
+    assert_eq!(pool.send(WhoAmI).await.unwrap().0, 1);
+
+    pool.stop().await;
+    assert_eq!(pool.size(), 0);
+    assert!(matches!(
+        pool.send(WhoAmI).await,
+        Err(SendError::ReceiverDisconnected)
+    ));
+}
+
+#[tokio::test]
+async fn concurrent_sends() {
+    let pool = pool(2, Router::RoundRobin);
+    let started = std::time::Instant::now();
+    // Pool is not borrowed while waiting for the response, so both requests are processed at once.
+    let (first, second) = tokio::join!(
+        pool.send(SlowWhoAmI(Duration::from_millis(100))),
+        pool.send(SlowWhoAmI(Duration::from_millis(100)))
+    );
+    assert_ne!(first.unwrap(), second.unwrap());
+    assert!(started.elapsed() < Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn clones_share_actors() {
+    let pool = pool(2, Router::RoundRobin);
+    let clone = pool.clone();
+    assert_eq!(pool.send(WhoAmI).await.unwrap(), (1, 1));
+    assert_eq!(clone.send(WhoAmI).await.unwrap(), (2, 1));
+    assert_eq!(clone.send(WhoAmI).await.unwrap(), (1, 2));
+
+    clone.resize(3);
+    assert_eq!(pool.size(), 3);
+}
+
+#[tokio::test]
+async fn stopped_actor_is_replaced() {
+    let pool = pool(2, Router::RoundRobin);
+    let mut stopped = pool.addresses()[0].clone();
+    stopped.stop().await;
+    stopped.wait_for_stop().await;
+
+    assert_eq!(pool.send(WhoAmI).await.unwrap().0, 3);
+    assert_eq!(pool.send(WhoAmI).await.unwrap().0, 2);
+    assert_eq!(pool.size(), 2);
+    assert_ne!(pool.addresses()[0], stopped);
+}
@@ -0,0 +1,82 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use messages::prelude::*;
+
+#[derive(Debug)]
+struct Payload(usize);
+
+#[derive(Debug, Default)]
+struct Accumulator {
+    total: Arc<AtomicUsize>,
+}
+
+impl Actor for Accumulator {}
+
+#[async_trait]
+impl Notifiable<Payload> for Accumulator {
+    async fn notify(&mut self, input: Payload, _: &Context<Self>) {
+        self.total.fetch_add(input.0, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl Handler<Payload> for Accumulator {
+    type Result = usize;
+
+    async fn handle(&mut self, input: Payload, _: &Context<Self>) -> usize {
+        self.total.fetch_add(input.0, Ordering::SeqCst) + input.0
+    }
+}
+
+#[derive(Debug, Default)]
+struct Counter {
+    count: Arc<AtomicUsize>,
+}
+
+impl Actor for Counter {}
+
+#[async_trait]
+impl Notifiable<Payload> for Counter {
+    async fn notify(&mut self, _input: Payload, _: &Context<Self>) {
+        self.count.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn heterogeneous_recipients() {
+    let total = Arc::new(AtomicUsize::new(0));
+    let count = Arc::new(AtomicUsize::new(0));
+
+    let accumulator = Accumulator {
+        total: total.clone(),
+    }
+    .spawn();
+    let counter = Counter {
+        count: count.clone(),
+    }
+    .spawn();
+
+    let mut subscribers: Vec<NotifyRecipient<Payload>> =
+        vec![accumulator.notify_recipient(), counter.notify_recipient()];
+    for subscriber in &mut subscribers {
+        subscriber.notify(Payload(10)).await.unwrap();
+    }
+
+    // Messages are processed in order, so the response means that notification was handled.
+    let mut recipient: Recipient<Payload, usize> = accumulator.recipient();
+    assert_eq!(recipient.send(Payload(1)).await.unwrap(), 11);
+
+    for subscriber in &mut subscribers {
+        subscriber.stop().await;
+        subscriber.wait_for_stop().await;
+        assert!(!subscriber.connected());
+    }
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert!(matches!(
+        recipient.send(Payload(1)).await,
+        Err(SendError::ReceiverDisconnected)
+    ));
+}
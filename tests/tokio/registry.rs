@@ -1,4 +1,10 @@
-use messages::prelude::*;
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use futures::StreamExt;
+use messages::{errors::RegistryError, prelude::*, registry::ServiceEvent};
 
 #[derive(Debug, Default)]
 struct PingActor;
@@ -18,17 +24,446 @@ impl Service for PingActor {
     const NAME: &'static str = "Ping";
 }
 
+#[derive(Debug, Default)]
+struct AnotherPingActor;
+
+impl Actor for AnotherPingActor {}
+
+impl Service for AnotherPingActor {
+    // Names don't have to be unique.
+    const NAME: &'static str = "Ping";
+}
+
 #[tokio::test]
 async fn get_from_registry() {
-    let mut address: Address<PingActor> = Registry::service().await;
+    let registry = Registry::new();
+    let mut address: Address<PingActor> = registry.service().await;
     let response = address.send(10).await.unwrap();
     assert_eq!(response, 10);
     address.stop().await;
     address.wait_for_stop().await;
 
     // Service must be restarted after stopping.
-    let mut address: Address<PingActor> = Registry::service().await;
+    let mut address: Address<PingActor> = registry.service().await;
     let response = address.send(10).await.unwrap();
     assert_eq!(response, 10);
     address.stop().await;
 }
+
+#[derive(Debug, Default)]
+struct OneShotActor;
+
+impl Actor for OneShotActor {}
+
+impl Service for OneShotActor {
+    const NAME: &'static str = "OneShot";
+    const RESTART: RestartPolicy = RestartPolicy::Never;
+}
+
+#[derive(Debug, Default)]
+struct BackoffActor;
+
+impl Actor for BackoffActor {}
+
+impl Service for BackoffActor {
+    const NAME: &'static str = "Backoff";
+    const RESTART: RestartPolicy = RestartPolicy::Backoff {
+        initial: Duration::from_millis(20),
+        max: Duration::from_millis(30),
+    };
+}
+
+#[tokio::test]
+async fn restart_events() {
+    let registry = Registry::new();
+    let mut events = registry.events();
+
+    let mut address: Address<PingActor> = registry.service().await;
+    let first_id = address.id();
+    address.stop().await;
+    address.wait_for_stop().await;
+    let address: Address<PingActor> = registry.service().await;
+    assert_eq!(registry.restarts::<PingActor>().await, 1);
+
+    assert_eq!(
+        events.next().await.unwrap(),
+        ServiceEvent::Started {
+            name: "Ping",
+            id: first_id
+        }
+    );
+    assert_eq!(
+        events.next().await.unwrap(),
+        ServiceEvent::Restarted {
+            name: "Ping",
+            id: address.id(),
+            restarts: 1
+        }
+    );
+
+    // Dropped streams don't receive events.
+    drop(events);
+    let _: Address<AnotherPingActor> = registry.service().await;
+}
+
+#[tokio::test]
+async fn never_restart_policy() {
+    let registry = Registry::new();
+    let mut address: Address<OneShotActor> = registry.service().await;
+    address.stop().await;
+    address.wait_for_stop().await;
+
+    assert!(matches!(
+        registry.try_service::<OneShotActor>().await,
+        Err(RegistryError::ServiceStopped { name: "OneShot" })
+    ));
+    assert_eq!(registry.restarts::<OneShotActor>().await, 0);
+
+    // Service can be started again once it's removed explicitly.
+    registry.unregister::<OneShotActor>().await;
+    assert!(registry.try_service::<OneShotActor>().await.is_ok());
+}
+
+#[tokio::test]
+async fn backoff_restart_policy() {
+    let registry = Registry::new();
+    for restarts in 0..3 {
+        let mut address: Address<BackoffActor> = registry.service().await;
+        address.stop().await;
+        address.wait_for_stop().await;
+        assert_eq!(registry.restarts::<BackoffActor>().await, restarts);
+    }
+
+    // Delays are 20ms, 30ms (limited by `max`) and 30ms again.
+    let started = std::time::Instant::now();
+    let _: Address<BackoffActor> = registry.service().await;
+    assert!(started.elapsed() >= Duration::from_millis(30));
+    assert_eq!(registry.restarts::<BackoffActor>().await, 3);
+}
+
+#[derive(Debug, Default)]
+struct ResettingBackoffActor;
+
+impl Actor for ResettingBackoffActor {}
+
+impl Service for ResettingBackoffActor {
+    const NAME: &'static str = "ResettingBackoff";
+    const RESTART: RestartPolicy = RestartPolicy::Backoff {
+        initial: Duration::from_millis(40),
+        max: Duration::from_millis(200),
+    };
+}
+
+#[tokio::test]
+async fn backoff_reset_after_successful_run() {
+    let registry = Registry::new();
+    let mut address: Address<ResettingBackoffActor> = registry.service().await;
+    for _ in 0..2 {
+        address.stop().await;
+        address.wait_for_stop().await;
+        // Registry is not blocked while the restart is delayed.
+        let (restarted, waited) = tokio::join!(registry.service(), async {
+            tokio::task::yield_now().await;
+            let started = std::time::Instant::now();
+            registry.restarts::<ResettingBackoffActor>().await;
+            started.elapsed()
+        });
+        address = restarted;
+        assert!(waited < Duration::from_millis(40));
+    }
+    assert_eq!(registry.restarts::<ResettingBackoffActor>().await, 2);
+
+    // Service was running for longer than `max`, so the next delay is `initial` rather than 160ms.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    address.stop().await;
+    address.wait_for_stop().await;
+    let started = std::time::Instant::now();
+    let _: Address<ResettingBackoffActor> = registry.service().await;
+    let elapsed = started.elapsed();
+    assert!(elapsed >= Duration::from_millis(40));
+    assert!(elapsed < Duration::from_millis(160));
+}
+
+#[derive(Debug, Default)]
+struct CrashingBackoffActor;
+
+impl Actor for CrashingBackoffActor {}
+
+impl Service for CrashingBackoffActor {
+    const NAME: &'static str = "CrashingBackoff";
+    const RESTART: RestartPolicy = RestartPolicy::Backoff {
+        initial: Duration::from_millis(40),
+        max: Duration::from_millis(200),
+    };
+}
+
+#[tokio::test]
+async fn backoff_not_reset_after_idle_period() {
+    let registry = Registry::new();
+    for _ in 0..2 {
+        let mut address: Address<CrashingBackoffActor> = registry.service().await;
+        address.stop().await;
+        address.wait_for_stop().await;
+    }
+
+    // Service stopped right after the restart, so the delay keeps growing even though
+    // it's requested again only after `max`.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let started = std::time::Instant::now();
+    let _: Address<CrashingBackoffActor> = registry.service().await;
+    assert!(started.elapsed() >= Duration::from_millis(80));
+    assert_eq!(registry.restarts::<CrashingBackoffActor>().await, 2);
+}
+
+#[tokio::test]
+async fn registries_are_independent() {
+    let first = Registry::new();
+    let second = Registry::new();
+
+    let address: Address<PingActor> = first.service().await;
+    assert_eq!(first.service::<PingActor>().await, address);
+    assert_ne!(second.service::<PingActor>().await, address);
+    assert_ne!(Registry::global().service::<PingActor>().await, address);
+}
+
+#[tokio::test]
+async fn register_and_unregister() {
+    let registry = Registry::new();
+    let address = PingActor.spawn();
+
+    assert!(registry.register(address.clone()).await.is_none());
+    assert_eq!(registry.service::<PingActor>().await, address);
+
+    assert_eq!(
+        registry.unregister::<PingActor>().await,
+        Some(address.clone())
+    );
+    assert!(registry.unregister::<PingActor>().await.is_none());
+    // Registered address was removed, so a new service is spawned.
+    assert_ne!(registry.service::<PingActor>().await, address);
+}
+
+#[tokio::test]
+async fn services_with_same_name() {
+    let registry = Registry::new();
+    let ping: Address<PingActor> = registry.try_service().await.unwrap();
+    let another: Address<AnotherPingActor> = registry.try_service().await.unwrap();
+    assert_ne!(ping.id(), another.id());
+    assert_eq!(registry.try_service::<PingActor>().await.unwrap(), ping);
+}
+
+#[tokio::test]
+async fn clear_stops_services() {
+    let registry = Registry::new();
+    let weak = registry.service::<PingActor>().await.downgrade();
+
+    registry.clear().await;
+    assert_eq!(weak.wait_for_stop().await, StopReason::Disconnected);
+}
+
+#[tokio::test]
+async fn system_registry() {
+    let system = ActorSystem::new();
+    let address: Address<PingActor> = system.registry().service().await;
+    assert_eq!(system.actors()[0].id, address.id());
+
+    let report = system.shutdown(Duration::from_secs(5)).await;
+    assert!(report.is_complete());
+    assert!(!address.connected());
+}
+
+#[tokio::test]
+async fn system_registry_tracks_registered_services() {
+    let system = ActorSystem::new();
+    let address = PingActor.spawn();
+    system.registry().register(address.clone()).await;
+    assert_eq!(system.actors().len(), 1);
+    assert_eq!(system.actors()[0].id, address.id());
+
+    let report = system.shutdown(Duration::from_secs(5)).await;
+    assert!(report.is_complete());
+    assert!(!address.connected());
+}
+
+/// Service that is created slowly and counts its instances.
+#[derive(Debug)]
+struct SlowService;
+
+static SLOW_SERVICE_CREATED: AtomicUsize = AtomicUsize::new(0);
+
+impl Actor for SlowService {}
+
+#[async_trait]
+impl AsyncService for SlowService {
+    const NAME: &'static str = "Slow";
+    type Error = ();
+
+    async fn create(registry: &Registry) -> Result<Self, ()> {
+        // Other services are available during creation.
+        let mut ping: Address<PingActor> = registry.service().await;
+        ping.send(1).await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        SLOW_SERVICE_CREATED.fetch_add(1, Ordering::SeqCst);
+        Ok(Self)
+    }
+}
+
+#[tokio::test]
+async fn async_service_is_created_once() {
+    let registry = Registry::new();
+    let (first, second, third) = futures::join!(
+        registry.try_service::<SlowService>(),
+        registry.try_service::<SlowService>(),
+        registry.try_service::<SlowService>(),
+    );
+    let first = first.unwrap();
+    assert_eq!(first, second.unwrap());
+    assert_eq!(first, third.unwrap());
+    assert_eq!(SLOW_SERVICE_CREATED.load(Ordering::SeqCst), 1);
+}
+
+/// Service that fails to be created on the first attempt.
+#[derive(Debug)]
+struct FlakyService;
+
+static FLAKY_SERVICE_ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+impl Actor for FlakyService {}
+
+#[async_trait]
+impl AsyncService for FlakyService {
+    const NAME: &'static str = "Flaky";
+    type Error = &'static str;
+
+    async fn create(_registry: &Registry) -> Result<Self, &'static str> {
+        if FLAKY_SERVICE_ATTEMPTS.fetch_add(1, Ordering::SeqCst) == 0 {
+            Err("Database is not available")
+        } else {
+            Ok(Self)
+        }
+    }
+}
+
+#[tokio::test]
+async fn async_service_creation_error() {
+    let registry = Registry::new();
+    assert_eq!(
+        registry.try_service::<FlakyService>().await.unwrap_err(),
+        RegistryError::CreationFailed("Database is not available")
+    );
+    // Creation is attempted again on the next request.
+    assert!(registry.try_service::<FlakyService>().await.is_ok());
+}
+
+#[derive(Debug)]
+struct UnavailableService;
+
+static UNAVAILABLE_SERVICE_ATTEMPTS: AtomicUsize = AtomicUsize::new(0);
+
+impl Actor for UnavailableService {}
+
+#[async_trait]
+impl AsyncService for UnavailableService {
+    const NAME: &'static str = "Unavailable";
+    type Error = &'static str;
+
+    async fn create(_registry: &Registry) -> Result<Self, &'static str> {
+        UNAVAILABLE_SERVICE_ATTEMPTS.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Err("Database is not available")
+    }
+}
+
+#[tokio::test]
+async fn concurrent_requests_share_creation_attempt() {
+    let registry = Registry::new();
+    let requests = (0..5).map(|_| registry.try_service::<UnavailableService>());
+    let results = futures::future::join_all(requests).await;
+
+    assert_eq!(UNAVAILABLE_SERVICE_ATTEMPTS.load(Ordering::SeqCst), 1);
+    for result in results {
+        assert_eq!(
+            result.unwrap_err(),
+            RegistryError::CreationFailed("Database is not available")
+        );
+    }
+
+    // Next request makes a new attempt.
+    assert!(registry.try_service::<UnavailableService>().await.is_err());
+    assert_eq!(UNAVAILABLE_SERVICE_ATTEMPTS.load(Ordering::SeqCst), 2);
+}
+
+#[derive(Debug)]
+struct Tenant;
+
+impl Actor for Tenant {}
+
+#[tokio::test]
+async fn named_actors() {
+    let registry = Registry::new();
+    let mut first = Tenant.spawn();
+    let second = Tenant.spawn();
+
+    assert!(registry.register_named("first", &first).is_none());
+    assert!(registry.register_named("second", &second).is_none());
+    // Keys are scoped by the actor type.
+    assert!(registry
+        .register_named("first", &PingActor.spawn())
+        .is_none());
+
+    assert_eq!(registry.lookup::<Tenant>("first"), Some(first.clone()));
+    assert!(registry.lookup::<Tenant>("third").is_none());
+    let keys: Vec<_> = registry
+        .named_actors::<Tenant>()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, ["first", "second"]);
+
+    first.stop().await;
+    first.wait_for_stop().await;
+    assert!(registry.lookup::<Tenant>("first").is_none());
+    assert_eq!(registry.named_actors::<Tenant>().len(), 1);
+
+    assert_eq!(registry.unregister_named::<Tenant>("second"), Some(second));
+    assert!(registry.named_actors::<Tenant>().is_empty());
+}
+
+#[tokio::test]
+async fn named_actor_replaced() {
+    let registry = Registry::new();
+    let mut first = Tenant.spawn();
+    let second = Tenant.spawn();
+
+    registry.register_named("tenant", &first);
+    assert_eq!(
+        registry.register_named("tenant", &second),
+        Some(first.clone())
+    );
+
+    // Stopping the replaced actor doesn't remove the new one.
+    first.stop().await;
+    first.wait_for_stop().await;
+    tokio::task::yield_now().await;
+    assert_eq!(registry.lookup::<Tenant>("tenant"), Some(second));
+}
+
+#[tokio::test]
+async fn named_actor_registered_again() {
+    let registry = Registry::new();
+    let mut tenant = Tenant.spawn();
+
+    registry.register_named("tenant", &tenant);
+    // Registering the same actor under the same key doesn't replace anything.
+    assert_eq!(
+        registry.register_named("tenant", &tenant),
+        Some(tenant.clone())
+    );
+    assert_eq!(registry.named_actors::<Tenant>().len(), 1);
+
+    tenant.stop().await;
+    tenant.wait_for_stop().await;
+    tokio::task::yield_now().await;
+    assert!(registry.lookup::<Tenant>("tenant").is_none());
+}
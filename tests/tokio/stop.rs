@@ -0,0 +1,162 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use messages::prelude::*;
+
+/// Actor that refuses to stop gracefully.
+#[derive(Debug, Default)]
+struct Stubborn {
+    stopping_calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Actor for Stubborn {
+    async fn stopping(&mut self, _reason: StopReason) -> ActorAction {
+        self.stopping_calls.fetch_add(1, Ordering::SeqCst);
+        ActorAction::KeepRunning
+    }
+}
+
+#[derive(Debug)]
+enum Command {
+    Stop,
+    Terminate,
+}
+
+#[async_trait]
+impl Notifiable<Command> for Stubborn {
+    async fn notify(&mut self, input: Command, context: &Context<Self>) {
+        match input {
+            Command::Stop => context.stop(),
+            Command::Terminate => context.terminate(),
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<()> for Stubborn {
+    type Result = ();
+
+    async fn handle(&mut self, _input: (), _: &Context<Self>) {}
+}
+
+#[tokio::test]
+async fn context_stop_can_be_vetoed() {
+    let stopping_calls = Arc::new(AtomicUsize::new(0));
+    let mut address = Stubborn {
+        stopping_calls: stopping_calls.clone(),
+    }
+    .spawn();
+
+    address.notify(Command::Stop).await.unwrap();
+    // Actor refused to stop, so it still processes messages.
+    address.send(()).await.unwrap();
+    assert_eq!(stopping_calls.load(Ordering::SeqCst), 1);
+
+    address.notify(Command::Terminate).await.unwrap();
+    let reason = address.wait_for_stop().await;
+    assert_eq!(reason, StopReason::Terminated);
+    assert_eq!(stopping_calls.load(Ordering::SeqCst), 2);
+    assert!(!address.connected());
+}
+
+#[tokio::test]
+async fn context_stop_with_full_mailbox() {
+    let context = Context::with_capacity(1);
+    let mut address = context.address();
+
+    address.notify(Command::Terminate).await.unwrap();
+    // Mailbox is full, but the actor is able to stop itself anyway.
+    assert!(matches!(
+        address.try_notify(Command::Stop),
+        Err(SendError::MailboxFull(_))
+    ));
+    tokio::spawn(context.run(Stubborn::default()));
+
+    assert_eq!(address.wait_for_stop().await, StopReason::Terminated);
+}
+
+/// Actor that agrees to stop and reports the stop reason.
+#[derive(Debug)]
+struct Reporter {
+    reason: async_channel::Sender<StopReason>,
+}
+
+#[async_trait]
+impl Actor for Reporter {
+    fn stopped(&mut self, reason: StopReason) {
+        self.reason.try_send(reason).unwrap();
+    }
+}
+
+#[async_trait]
+impl Notifiable<()> for Reporter {
+    async fn notify(&mut self, _input: (), context: &Context<Self>) {
+        context.stop();
+    }
+}
+
+fn reporter() -> (Reporter, async_channel::Receiver<StopReason>) {
+    let (sender, receiver) = async_channel::bounded(1);
+    (Reporter { reason: sender }, receiver)
+}
+
+#[tokio::test]
+async fn stop_reasons() {
+    // Stop requested via address.
+    let (actor, reason) = reporter();
+    let mut address = actor.spawn();
+    address.stop().await;
+    assert_eq!(address.wait_for_stop().await, StopReason::Requested);
+    assert_eq!(reason.recv().await.unwrap(), StopReason::Requested);
+
+    // Actor stopped itself.
+    let (actor, reason) = reporter();
+    let mut address = actor.spawn();
+    address.notify(()).await.unwrap();
+    assert_eq!(address.wait_for_stop().await, StopReason::SelfStopped);
+    assert_eq!(reason.recv().await.unwrap(), StopReason::SelfStopped);
+
+    // All the addresses were dropped.
+    let (actor, reason) = reporter();
+    let weak = actor.spawn().downgrade();
+    assert_eq!(weak.wait_for_stop().await, StopReason::Disconnected);
+    assert_eq!(reason.recv().await.unwrap(), StopReason::Disconnected);
+
+    // Context was never launched.
+    let context: Context<Reporter> = Context::new();
+    let address = context.address();
+    drop(context);
+    assert_eq!(address.wait_for_stop().await, StopReason::Dropped);
+}
+
+#[tokio::test]
+async fn stop_before_run() {
+    // Stop requested via context before the actor was started.
+    let context = Context::new();
+    let address = context.address();
+    context.stop();
+    let (actor, reason) = reporter();
+    let outcome = context.run(actor).await;
+    assert_eq!(outcome.reason, StopReason::SelfStopped);
+    assert_eq!(reason.recv().await.unwrap(), StopReason::SelfStopped);
+    assert_eq!(address.wait_for_stop().await, StopReason::SelfStopped);
+
+    // Same for termination.
+    let context = Context::new();
+    context.terminate();
+    let (actor, reason) = reporter();
+    assert_eq!(context.run(actor).await.reason, StopReason::Terminated);
+    assert_eq!(reason.recv().await.unwrap(), StopReason::Terminated);
+
+    // Stop requested via address before the actor was started.
+    let context = Context::new();
+    let mut address = context.address();
+    address.stop().await;
+    let (actor, reason) = reporter();
+    tokio::spawn(context.run(actor));
+    assert_eq!(address.wait_for_stop().await, StopReason::Requested);
+    assert_eq!(reason.recv().await.unwrap(), StopReason::Requested);
+}
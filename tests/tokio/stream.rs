@@ -0,0 +1,90 @@
+use futures::stream;
+use messages::prelude::*;
+
+#[derive(Debug, Default)]
+struct Collector {
+    events: Vec<String>,
+}
+
+impl Actor for Collector {}
+
+#[async_trait]
+impl StreamHandler<u32> for Collector {
+    async fn handle(&mut self, input: u32, _: &Context<Self>) {
+        self.events.push(input.to_string());
+    }
+
+    async fn started(&mut self, _: &Context<Self>) {
+        self.events.push("started".into());
+    }
+
+    async fn finished(&mut self, _: &Context<Self>) {
+        self.events.push("finished".into());
+    }
+}
+
+#[derive(Debug)]
+struct Events;
+
+#[async_trait]
+impl Handler<Events> for Collector {
+    type Result = Vec<String>;
+
+    async fn handle(&mut self, _input: Events, _: &Context<Self>) -> Vec<String> {
+        self.events.clone()
+    }
+}
+
+#[derive(Debug)]
+struct AddStream(Vec<u32>);
+
+#[async_trait]
+impl Notifiable<AddStream> for Collector {
+    async fn notify(&mut self, input: AddStream, context: &Context<Self>) {
+        context.add_stream(stream::iter(input.0));
+    }
+}
+
+#[tokio::test]
+async fn stream_is_handled() {
+    let context = Context::new();
+    context.add_stream(stream::iter(vec![1, 2]));
+    let mut address = context.spawn(Collector::default());
+
+    // Stream added from the handler is polled as well.
+    address.notify(AddStream(vec![3])).await.unwrap();
+
+    // Streams are polled concurrently with the mailbox, so wait until they are exhausted.
+    let events = loop {
+        let events = address.send(Events).await.unwrap();
+        if events.len() == 7 {
+            break events;
+        }
+        tokio::task::yield_now().await;
+    };
+    // Streams are polled concurrently, so only the order within a single stream is guaranteed.
+    let position = |event: &str| events.iter().position(|e| e == event).unwrap();
+    assert!(position("started") < position("1"));
+    assert!(position("1") < position("2"));
+    let mut sorted = events.clone();
+    sorted.sort();
+    assert_eq!(
+        sorted,
+        ["1", "2", "3", "finished", "finished", "started", "started"]
+    );
+
+    address.stop().await;
+    address.wait_for_stop().await;
+}
+
+#[tokio::test]
+async fn stream_does_not_keep_actor_alive() {
+    let context = Context::new();
+    context.add_stream(stream::pending::<u32>());
+    let address = context.spawn(Collector::default());
+
+    let weak = address.downgrade();
+    drop(address);
+    weak.wait_for_stop().await;
+    assert!(!weak.connected());
+}
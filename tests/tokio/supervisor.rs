@@ -0,0 +1,209 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use messages::{errors::SupervisorError, prelude::*};
+
+/// Actor that panics on demand.
+#[derive(Debug)]
+struct Worker {
+    processed: usize,
+}
+
+impl Actor for Worker {}
+
+#[derive(Debug)]
+struct Fail;
+
+#[async_trait]
+impl Handler<Fail> for Worker {
+    type Result = ();
+
+    async fn handle(&mut self, _input: Fail, _: &Context<Self>) {
+        panic!("Requested failure");
+    }
+}
+
+#[async_trait]
+impl Handler<()> for Worker {
+    type Result = usize;
+
+    async fn handle(&mut self, _input: (), _: &Context<Self>) -> usize {
+        self.processed += 1;
+        self.processed
+    }
+}
+
+fn supervisor(strategy: RestartStrategy) -> Supervisor {
+    Supervisor::new(strategy)
+        .with_restart_intensity(3, Duration::from_secs(5))
+        .with_backoff(Duration::from_millis(1), Duration::from_millis(10))
+}
+
+/// Adds a worker that counts its starts.
+fn add_worker(supervisor: &mut Supervisor) -> (Address<Worker>, Arc<AtomicUsize>) {
+    let starts = Arc::new(AtomicUsize::new(0));
+    let starts_clone = starts.clone();
+    let address = supervisor.add_child(move |_| {
+        starts_clone.fetch_add(1, Ordering::SeqCst);
+        Worker { processed: 0 }
+    });
+    (address, starts)
+}
+
+#[tokio::test]
+async fn one_for_one() {
+    let mut supervisor = supervisor(RestartStrategy::OneForOne);
+    let (mut first, first_starts) = add_worker(&mut supervisor);
+    let (mut second, second_starts) = add_worker(&mut supervisor);
+    let handle = supervisor.spawn();
+
+    assert_eq!(second.send(()).await.unwrap(), 1);
+    assert!(matches!(
+        first.send(Fail).await,
+        Err(SendError::HandlerPanicked)
+    ));
+    // Address of the restarted actor is still valid, and the state is reset.
+    assert_eq!(first.send(()).await.unwrap(), 1);
+    assert_eq!(first_starts.load(Ordering::SeqCst), 2);
+    // Other actor was not affected.
+    assert_eq!(second.send(()).await.unwrap(), 2);
+    assert_eq!(second_starts.load(Ordering::SeqCst), 1);
+
+    first.stop().await;
+    second.stop().await;
+    assert_eq!(first.wait_for_stop().await, StopReason::Requested);
+    handle.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn one_for_all() {
+    let mut supervisor = supervisor(RestartStrategy::OneForAll);
+    let (mut first, first_starts) = add_worker(&mut supervisor);
+    let (mut second, second_starts) = add_worker(&mut supervisor);
+    let handle = supervisor.spawn();
+
+    assert_eq!(first.send(()).await.unwrap(), 1);
+    assert!(second.send(Fail).await.is_err());
+    // Both actors are restarted.
+    assert_eq!(second.send(()).await.unwrap(), 1);
+    assert_eq!(first.send(()).await.unwrap(), 1);
+    assert_eq!(first_starts.load(Ordering::SeqCst), 2);
+    assert_eq!(second_starts.load(Ordering::SeqCst), 2);
+
+    first.stop().await;
+    second.stop().await;
+    handle.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn rest_for_one() {
+    let mut supervisor = supervisor(RestartStrategy::RestForOne);
+    let (mut first, first_starts) = add_worker(&mut supervisor);
+    let (mut second, second_starts) = add_worker(&mut supervisor);
+    let (mut third, third_starts) = add_worker(&mut supervisor);
+    let handle = supervisor.spawn();
+
+    assert_eq!(first.send(()).await.unwrap(), 1);
+    assert_eq!(third.send(()).await.unwrap(), 1);
+    assert!(second.send(Fail).await.is_err());
+    assert_eq!(second.send(()).await.unwrap(), 1);
+    assert_eq!(third.send(()).await.unwrap(), 1);
+    // Actors added before the failed one are not affected.
+    assert_eq!(first.send(()).await.unwrap(), 2);
+    assert_eq!(first_starts.load(Ordering::SeqCst), 1);
+    assert_eq!(second_starts.load(Ordering::SeqCst), 2);
+    assert_eq!(third_starts.load(Ordering::SeqCst), 2);
+
+    for address in [&mut first, &mut second, &mut third] {
+        address.stop().await;
+    }
+    handle.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn restart_intensity() {
+    let mut supervisor = supervisor(RestartStrategy::OneForOne);
+    let (mut failing, _) = add_worker(&mut supervisor);
+    let (healthy, _) = add_worker(&mut supervisor);
+    let handle = supervisor.spawn();
+
+    // 3 restarts are allowed, 4th failure stops the supervisor.
+    for _ in 0..4 {
+        assert!(failing.send(Fail).await.is_err());
+    }
+    assert_eq!(
+        handle.await.unwrap(),
+        Err(SupervisorError::RestartIntensityExceeded)
+    );
+    assert_eq!(failing.wait_for_stop().await, StopReason::Panicked);
+    assert_eq!(
+        healthy.wait_for_stop().await,
+        StopReason::SupervisorShutdown
+    );
+}
+
+/// Actor that takes a while to stop.
+#[derive(Debug)]
+struct SlowStopper {
+    stopped: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Actor for SlowStopper {
+    async fn stopping(&mut self, _reason: StopReason) -> ActorAction {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        ActorAction::Stop
+    }
+
+    fn stopped(&mut self, _reason: StopReason) {
+        self.stopped.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl Handler<()> for SlowStopper {
+    type Result = ();
+
+    async fn handle(&mut self, _input: (), _: &Context<Self>) {}
+}
+
+#[tokio::test]
+async fn sibling_waiting_for_restart_is_not_interrupted() {
+    let mut supervisor = Supervisor::new(RestartStrategy::OneForAll)
+        .with_restart_intensity(3, Duration::from_secs(5))
+        .with_backoff(Duration::from_millis(100), Duration::from_secs(1));
+    let (mut failing, _) = add_worker(&mut supervisor);
+    let starts = Arc::new(AtomicUsize::new(0));
+    let stopped = Arc::new(AtomicUsize::new(0));
+    let (starts_clone, stopped_clone) = (starts.clone(), stopped.clone());
+    let mut sibling = supervisor.add_child(move |_| {
+        starts_clone.fetch_add(1, Ordering::SeqCst);
+        SlowStopper {
+            stopped: stopped_clone.clone(),
+        }
+    });
+    let handle = supervisor.spawn();
+    sibling.send(()).await.unwrap();
+
+    // The second failure happens once the failing actor is restarted (after 100ms),
+    // while the sibling is still waiting for its own restart (it stops in 50ms
+    // and then waits for 100ms).
+    let first = failing.try_send(Fail).unwrap();
+    let second = failing.try_send(Fail).unwrap();
+    assert!(first.await.is_err());
+    assert!(second.await.is_err());
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    sibling.send(()).await.unwrap();
+    assert_eq!(starts.load(Ordering::SeqCst), 2);
+    assert_eq!(stopped.load(Ordering::SeqCst), 1);
+
+    failing.stop().await;
+    sibling.stop().await;
+    handle.await.unwrap().unwrap();
+}
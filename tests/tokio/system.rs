@@ -0,0 +1,113 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use messages::prelude::*;
+
+use super::Unstoppable;
+
+#[derive(Debug)]
+struct Recorder {
+    name: &'static str,
+    stopped: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl Actor for Recorder {
+    fn stopped(&mut self, _reason: StopReason) {
+        self.stopped.lock().unwrap().push(self.name);
+    }
+}
+
+#[tokio::test]
+async fn shutdown_in_reverse_order() {
+    let system = ActorSystem::new();
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+    let addresses: Vec<_> = ["database", "cache", "server"]
+        .iter()
+        .map(|&name| {
+            let context = Context::builder().name(name).build();
+            system.spawn_with_context(
+                context,
+                Recorder {
+                    name,
+                    stopped: stopped.clone(),
+                },
+            )
+        })
+        .collect();
+
+    let names: Vec<_> = system
+        .actors()
+        .into_iter()
+        .map(|actor| actor.name.unwrap())
+        .collect();
+    assert_eq!(names, ["database", "cache", "server"]);
+
+    let report = system.shutdown(Duration::from_secs(5)).await;
+    assert!(report.is_complete());
+    assert_eq!(*stopped.lock().unwrap(), ["server", "cache", "database"]);
+    let ids: Vec<_> = report.stopped.iter().map(|actor| actor.id).collect();
+    let expected: Vec<_> = addresses.iter().rev().map(Address::id).collect();
+    assert_eq!(ids, expected);
+    assert!(system.actors().is_empty());
+}
+
+#[tokio::test]
+async fn stopped_actors_are_not_listed() {
+    let system = ActorSystem::new();
+    let first = system.spawn(Unstoppable);
+    let second = system.spawn(Unstoppable);
+
+    drop(first);
+    // Wait until the actor notices that all of its addresses are dropped.
+    while system.actors().len() != 1 {
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(system.actors()[0].id, second.id());
+}
+
+#[tokio::test]
+async fn shutdown_reports_actors_that_did_not_stop() {
+    let system = ActorSystem::new();
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+    let unstoppable = system.spawn(Unstoppable);
+    system.spawn(Recorder {
+        name: "recorder",
+        stopped: stopped.clone(),
+    });
+
+    let report = system.shutdown(Duration::from_millis(50)).await;
+    assert!(!report.is_complete());
+    assert_eq!(report.timed_out.len(), 1);
+    assert_eq!(report.timed_out[0].id, unstoppable.id());
+    assert_eq!(report.stopped.len(), 1);
+    assert_eq!(*stopped.lock().unwrap(), ["recorder"]);
+
+    // Actor that refused to stop is still tracked.
+    assert_eq!(system.actors().len(), 1);
+}
+
+#[cfg(all(unix, feature = "signal"))]
+#[tokio::test]
+async fn shutdown_on_signal() {
+    let system = ActorSystem::new();
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+    let _address = system.spawn(Recorder {
+        name: "recorder",
+        stopped: stopped.clone(),
+    });
+
+    let (report, ()) = futures::join!(system.shutdown_on_signal(Duration::from_secs(5)), async {
+        // Give the system some time to install the signal handler.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let status = std::process::Command::new("kill")
+            .arg("-TERM")
+            .arg(std::process::id().to_string())
+            .status()
+            .unwrap();
+        assert!(status.success());
+    });
+    assert!(report.is_complete());
+    assert_eq!(*stopped.lock().unwrap(), ["recorder"]);
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use messages::prelude::*;
+
+#[derive(Debug, Default)]
+struct Ticker {
+    ticks: usize,
+    timer: Option<TimerHandle>,
+}
+
+impl Actor for Ticker {}
+
+#[derive(Debug)]
+struct Tick;
+
+#[derive(Debug)]
+enum Command {
+    StartInterval,
+    Delay(Duration),
+    Cancel,
+    Ticks,
+}
+
+#[async_trait]
+impl Notifiable<Tick> for Ticker {
+    async fn notify(&mut self, _input: Tick, _: &Context<Self>) {
+        self.ticks += 1;
+    }
+}
+
+#[async_trait]
+impl Handler<Command> for Ticker {
+    type Result = usize;
+
+    async fn handle(&mut self, input: Command, context: &Context<Self>) -> usize {
+        match input {
+            Command::StartInterval => {
+                self.timer = Some(context.notify_interval(Duration::from_millis(10), || Tick));
+            }
+            Command::Delay(delay) => {
+                self.timer = Some(context.notify_later(Tick, delay));
+            }
+            Command::Cancel => {
+                if let Some(timer) = self.timer.take() {
+                    timer.cancel();
+                }
+            }
+            Command::Ticks => {}
+        }
+        self.ticks
+    }
+}
+
+#[tokio::test]
+async fn notify_later() {
+    let mut address = Ticker::default().spawn();
+
+    address
+        .send(Command::Delay(Duration::from_millis(10)))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(address.send(Command::Ticks).await.unwrap(), 1);
+
+    // Cancelled timer must not fire.
+    address
+        .send(Command::Delay(Duration::from_millis(20)))
+        .await
+        .unwrap();
+    address.send(Command::Cancel).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(address.send(Command::Ticks).await.unwrap(), 1);
+
+    address.stop().await;
+    address.wait_for_stop().await;
+}
+
+#[tokio::test]
+async fn notify_interval() {
+    let mut address = Ticker::default().spawn();
+
+    address.send(Command::StartInterval).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    address.send(Command::Cancel).await.unwrap();
+    // Ticks that were sent before the cancellation may still be in the mailbox.
+    let ticks = address.send(Command::Ticks).await.unwrap();
+    assert!(ticks >= 2, "Expected several ticks, got {}", ticks);
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(address.send(Command::Ticks).await.unwrap(), ticks);
+
+    address.stop().await;
+    address.wait_for_stop().await;
+}
+
+#[tokio::test]
+async fn timers_do_not_keep_actor_alive() {
+    let mut address = Ticker::default().spawn();
+    address.send(Command::StartInterval).await.unwrap();
+
+    let weak = address.downgrade();
+    drop(address);
+    weak.wait_for_stop().await;
+    assert!(weak.upgrade().is_none());
+}
@@ -0,0 +1,50 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use messages::prelude::*;
+
+/// Actor that stores its own address, as it is often done in
+/// ring or parent/child topologies.
+#[derive(Debug)]
+struct SelfAware {
+    me: WeakAddress<Self>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl Actor for SelfAware {
+    fn stopped(&mut self, _reason: StopReason) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl Handler<()> for SelfAware {
+    type Result = bool;
+
+    async fn handle(&mut self, _input: (), _: &Context<Self>) -> bool {
+        self.me.upgrade().is_some()
+    }
+}
+
+#[tokio::test]
+async fn weak_address_does_not_keep_actor_alive() {
+    let stopped = Arc::new(AtomicBool::new(false));
+    let stopped_clone = stopped.clone();
+    let mut address = SelfAware::create_and_spawn(move |ctx| SelfAware {
+        me: ctx.weak_address(),
+        stopped: stopped_clone,
+    });
+
+    // While there is a strong address, weak one can be upgraded.
+    assert!(address.send(()).await.unwrap());
+
+    let weak = address.downgrade();
+    drop(address);
+    weak.wait_for_stop().await;
+
+    assert!(stopped.load(Ordering::SeqCst));
+    assert!(!weak.connected());
+    assert!(weak.upgrade().is_none());
+}